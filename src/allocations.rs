@@ -0,0 +1,168 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell, fmt::Display, sync::atomic::{AtomicBool, Ordering}};
+
+/// Wraps the system allocator, counting what each thread allocates once [`enable`] has been called.
+///
+/// Nothing is counted unless a binary installs it with `#[global_allocator]`.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Per thread, so that days run in parallel don't count each other's allocations.
+struct Counters {
+    count: Cell<u64>,
+    bytes: Cell<u64>,
+    /// Signed, as memory can be freed on a different thread from the one that allocated it.
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters { count: Cell::new(0), bytes: Cell::new(0), live: Cell::new(0), peak: Cell::new(0) }
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // a thread that's shutting down can still free memory after its counters are gone
+    let _ = COUNTERS.try_with(|counters| {
+        if allocated > 0 {
+            counters.count.set(counters.count.get() + 1);
+            counters.bytes.set(counters.bytes.get() + allocated as u64);
+        }
+
+        let live = counters.live.get() + allocated as i64 - freed as i64;
+        counters.live.set(live);
+        counters.peak.set(counters.peak.get().max(live));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Starts counting. Only worth doing if [`CountingAllocator`] is the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// What a stretch of code allocated. A reallocation counts as allocating its new size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most memory held at once, over and above what was held beforehand.
+    pub peak: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocations, {} allocated, {} peak", self.count, Bytes(self.bytes), Bytes(self.peak))
+    }
+}
+
+/// A number of bytes in the largest unit that keeps it above one, e.g. `1.50 MiB`.
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+
+        let (size, unit) = UNITS.iter()
+            .scan(self.0 as f64, |size, unit| {
+                *size /= 1024.0;
+                Some((*size, unit))
+            })
+            .find(|&(size, unit)| size < 1024.0 || unit == UNITS.last().unwrap())
+            .expect("There's always a last unit");
+
+        f.pad(&format!("{size:.2} {unit}"))
+    }
+}
+
+/// Runs `f`, counting what it allocates on this thread, or `None` if counting isn't enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let (count, bytes, live) = COUNTERS.with(|counters| {
+        counters.peak.set(counters.live.get());
+        (counters.count.get(), counters.bytes.get(), counters.live.get())
+    });
+
+    let result = f();
+
+    let allocations = COUNTERS.with(|counters| Allocations {
+        count: counters.count.get() - count,
+        bytes: counters.bytes.get() - bytes,
+        peak: (counters.peak.get() - live).max(0) as u64,
+    });
+
+    (result, Some(allocations))
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{enable, measure, Allocations, Bytes};
+
+    #[test]
+    fn counts_what_a_closure_allocates() {
+        enable();
+
+        let (_, allocations) = measure(|| {
+            let small = black_box(vec![0u8; 100]);
+            drop(small);
+            black_box(vec![0u8; 1000])
+        });
+        assert_eq!(allocations, Some(Allocations { count: 2, bytes: 1100, peak: 1000 }));
+
+        // freeing what was held before doesn't make the peak negative
+        let held = black_box(vec![0u8; 500]);
+        let (_, allocations) = measure(|| drop(held));
+        assert_eq!(allocations, Some(Allocations { count: 0, bytes: 0, peak: 0 }));
+    }
+
+    #[test]
+    fn bytes_are_readable() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00 MiB");
+        assert_eq!(format!("{:>10}", Bytes(0)), "       0 B");
+    }
+}
@@ -0,0 +1,145 @@
+use std::{collections::BTreeMap, fmt::Display, io::ErrorKind, path::{Path, PathBuf}};
+
+use crate::{input::INPUT_DIR, solution::{Answer, Part}};
+
+pub fn default_path() -> PathBuf {
+    [INPUT_DIR, "answers.txt"].iter().collect()
+}
+
+/// Expected answers, one `<day>.<part>: <answer>` per line.
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, error: std::io::Error },
+    Parse { path: PathBuf, line: usize, text: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => write!(f, "couldn't access answers file {}: {error}", path.display()),
+            AnswersError::Parse { path, line, text } => {
+                write!(f, "{}:{line}: expected `<day>.<part>: <answer>`, found `{text}`", path.display())
+            },
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+fn parse_line(line: &str) -> Option<((u8, Part), String)> {
+    let (key, answer) = line.split_once(':')?;
+    let (day, part) = key.trim().split_once('.')?;
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+
+    Some(((day.parse().ok()?, part), answer.trim().to_owned()))
+}
+
+impl Answers {
+    /// A missing file is treated as having no answers yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(AnswersError::Io { path: path.to_owned(), error }),
+        };
+
+        contents.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                parse_line(line)
+                    .ok_or_else(|| AnswersError::Parse { path: path.to_owned(), line: i + 1, text: line.to_owned() })
+            })
+            .try_collect()
+            .map(Self)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let contents = self.0.iter()
+            .map(|((day, part), answer)| format!("{day}.{part}: {answer}\n"))
+            .collect::<String>();
+
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(path, contents))
+            .map_err(|error| AnswersError::Io { path: path.to_owned(), error })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: &Answer) {
+        self.0.insert((day, part), answer.to_string());
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+    Recorded,
+}
+
+impl Status {
+    pub fn check(answers: &Answers, day: u8, part: Part, answer: &Answer) -> Self {
+        match answers.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Status::Pass,
+            Some(expected) => Status::Fail { expected: expected.to_owned() },
+            None => Status::Missing,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => f.pad("pass"),
+            Status::Fail { .. } => f.pad("FAIL"),
+            Status::Missing => f.pad("missing"),
+            Status::Recorded => f.pad("recorded"),
+        }
+    }
+}
+
+pub struct Verified {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub status: Status,
+}
+
+pub fn print_summary(results: &[Verified]) {
+    println!("{:>3}  {:>4}  {:<8}  {:<20}  Expected", "Day", "Part", "Status", "Answer");
+
+    results.iter()
+        .for_each(|Verified { day, part, answer, status }| {
+            let expected = match status {
+                Status::Fail { expected } => expected.as_str(),
+                _ => "",
+            };
+
+            let row = format!("{day:>3}  {part:>4}  {status:<8}  {:<20}  {expected}", answer.to_string());
+            println!("{}", row.trim_end());
+        });
+
+    let count = |f: fn(&Status) -> bool| results.iter().filter(|r| f(&r.status)).count();
+
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing, {} recorded",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail { .. })),
+        count(|s| *s == Status::Missing),
+        count(|s| *s == Status::Recorded),
+    );
+}
@@ -0,0 +1,154 @@
+use std::{fmt::Display, hint::black_box, iter::Sum, time::{Duration, Instant}};
+
+use crate::{allocations::{self, Allocations, Bytes}, parse::ParseError, solution::{Day, Part}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part {part}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self {
+            min: *samples.first().expect("No samples were taken"),
+            median: samples[samples.len() / 2],
+            max: *samples.last().expect("No samples were taken"),
+        }
+    }
+}
+
+impl Sum for Timings {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, t| Self {
+            min: acc.min + t.min,
+            median: acc.median + t.median,
+            max: acc.max + t.max,
+        })
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    /// Allocations are counted on the first iteration only, if they're counted at all.
+    pub stages: Vec<(Stage, Timings, Option<Allocations>)>,
+}
+
+/// Totals for a set of stages. The peak is the largest of theirs, as they don't run at the same time.
+fn total_allocations(allocations: impl Iterator<Item = Option<Allocations>>) -> Option<Allocations> {
+    allocations.reduce(|acc, allocations| Some(Allocations {
+        count: acc?.count + allocations?.count,
+        bytes: acc?.bytes + allocations?.bytes,
+        peak: acc?.peak.max(allocations?.peak),
+    }))?
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+/// Parses and solves `input` `iterations` times, timing each stage separately.
+pub fn bench_day(day: &Day, input: &str, parts: &[Part], iterations: usize) -> Result<DayBench, ParseError> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+    let mut parse_allocations = None;
+    let mut part_allocations = vec![None; parts.len()];
+
+    for _ in 0..iterations {
+        let ((parsed, elapsed), allocations) = allocations::measure(|| time(|| day.parse(black_box(input))));
+        let parsed = parsed?;
+        parse_samples.push(elapsed);
+        parse_allocations = parse_allocations.or(allocations);
+
+        parts.iter()
+            .zip(part_samples.iter_mut().zip(part_allocations.iter_mut()))
+            .for_each(|(&part, (samples, part_allocations))| {
+                let ((_, elapsed), allocations) = allocations::measure(|| time(|| day.solve(part, &parsed)));
+                samples.push(elapsed);
+                *part_allocations = part_allocations.or(allocations);
+            });
+    }
+
+    let part_stages = parts.iter()
+        .zip(part_samples)
+        .zip(part_allocations)
+        .map(|((&part, samples), allocations)| (Stage::Part(part), samples, allocations));
+    let stages = std::iter::once((Stage::Parse, parse_samples, parse_allocations))
+        .chain(part_stages)
+        .map(|(stage, samples, allocations)| (stage, Timings::from_samples(samples), allocations))
+        .collect();
+
+    Ok(DayBench { day: day.day, stages })
+}
+
+fn print_row(label: &str, stage: &dyn Display, timings: &Timings, allocations: Option<Allocations>) {
+    print!(
+        "{label:>6}  {stage:<6}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+        timings.min, timings.median, timings.max,
+    );
+
+    match allocations {
+        Some(Allocations { count, bytes, peak }) => println!("  {count:>10}  {:>12}  {:>12}", Bytes(bytes), Bytes(peak)),
+        None => println!(),
+    }
+}
+
+pub fn print_report(benches: &[DayBench]) {
+    print!("{:>6}  {:<6}  {:>12}  {:>12}  {:>12}", "Day", "Stage", "Min", "Median", "Max");
+    if allocations::is_enabled() {
+        println!("  {:>10}  {:>12}  {:>12}", "Allocs", "Allocated", "Peak");
+    } else {
+        println!();
+    }
+
+    benches.iter()
+        .for_each(|bench| {
+            bench.stages.iter()
+                .for_each(|(stage, timings, allocations)| print_row(&bench.day.to_string(), stage, timings, *allocations));
+        });
+
+    println!();
+
+    let mut stages = benches.iter()
+        .flat_map(|bench| bench.stages.iter().map(|(stage, _, _)| *stage))
+        .collect::<Vec<_>>();
+    stages.sort();
+    stages.dedup();
+
+    stages.into_iter()
+        .for_each(|stage| {
+            let stages = || benches.iter()
+                .flat_map(|bench| &bench.stages)
+                .filter(|(s, _, _)| *s == stage);
+            let total = stages().map(|(_, timings, _)| *timings).sum::<Timings>();
+            let allocations = total_allocations(stages().map(|(_, _, allocations)| *allocations));
+
+            print_row("Total", &stage, &total, allocations);
+        });
+
+    let stages = || benches.iter().flat_map(|bench| &bench.stages);
+    let total = stages().map(|(_, timings, _)| *timings).sum::<Timings>();
+    let allocations = total_allocations(stages().map(|(_, _, allocations)| *allocations));
+
+    print_row("Total", &"all", &total, allocations);
+}
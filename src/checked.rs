@@ -0,0 +1,167 @@
+use std::{any::type_name, fmt::Display, ops::{Add, Mul}, sync::atomic::{AtomicBool, Ordering}};
+
+use num_traits::{CheckedAdd, CheckedMul, Zero};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(test)]
+thread_local! {
+    /// Checking for just this thread, so that tests can turn it on without turning it on under each other.
+    static ENABLED_HERE: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Makes arithmetic done through this module panic, saying what overflowed, instead of doing what plain
+/// arithmetic does: panicking without saying what in debug builds, and wrapping round to a wrong answer in
+/// release ones.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    #[cfg(test)]
+    if ENABLED_HERE.get() {
+        return true;
+    }
+
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Checking on this thread until it's dropped, when it goes back to how it was, even if that's by panicking.
+#[cfg(test)]
+pub struct EnabledHere {
+    outer: bool,
+}
+
+#[cfg(test)]
+pub fn enable_here() -> EnabledHere {
+    EnabledHere { outer: ENABLED_HERE.replace(true) }
+}
+
+#[cfg(test)]
+impl Drop for EnabledHere {
+    fn drop(&mut self) {
+        ENABLED_HERE.set(self.outer);
+    }
+}
+
+/// An integer type that can be added and multiplied either way.
+pub trait Integer: Add<Output = Self> + Mul<Output = Self> + CheckedAdd + CheckedMul + Zero + Display + Copy {}
+
+impl<T: Add<Output = T> + Mul<Output = T> + CheckedAdd + CheckedMul + Zero + Display + Copy> Integer for T {}
+
+#[track_caller]
+fn overflow<T>(what: &str, a: T, op: &str, b: T) -> !
+where
+    T: Display,
+{
+    panic!("overflow working out {what}: {a} {op} {b} doesn't fit in {}", type_name::<T>())
+}
+
+/// `a + b`, where `what` is what's being worked out, e.g. "the total distance".
+#[track_caller]
+pub fn add<T: Integer>(a: T, b: T, what: &str) -> T {
+    if !is_enabled() {
+        return a + b;
+    }
+
+    match a.checked_add(&b) {
+        Some(sum) => sum,
+        None => overflow(what, a, "+", b),
+    }
+}
+
+/// `a * b`, where `what` is what's being worked out.
+#[track_caller]
+pub fn mul<T: Integer>(a: T, b: T, what: &str) -> T {
+    if !is_enabled() {
+        return a * b;
+    }
+
+    match a.checked_mul(&b) {
+        Some(product) => product,
+        None => overflow(what, a, "*", b),
+    }
+}
+
+/// The sum of `values`, where `what` is what's being totalled.
+#[track_caller]
+pub fn sum<T: Integer>(values: impl IntoIterator<Item = T>, what: &str) -> T {
+    // a loop rather than a fold, as closures don't pass on the caller's location
+    let mut total = T::zero();
+    for value in values {
+        total = add(total, value, what);
+    }
+
+    total
+}
+
+/// [`sum`] at the end of an iterator chain.
+pub trait Sum: Iterator + Sized {
+    #[track_caller]
+    fn checked_sum(self, what: &str) -> Self::Item;
+}
+
+impl<I: Iterator<Item = T>, T: Integer> Sum for I {
+    #[track_caller]
+    fn checked_sum(self, what: &str) -> T {
+        sum(self, what)
+    }
+}
+
+/// Enables checking on this thread and asserts that `f` overflows working out `what`.
+#[cfg(test)]
+pub fn assert_overflows<T>(what: &str, f: impl FnOnce() -> T) {
+    let _checking = enable_here();
+    match crate::runner::catch_panic(f) {
+        Ok(_) => panic!("expected an overflow working out {what}"),
+        Err(message) => assert!(message.contains(&format!("overflow working out {what}:")), "{message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::catch_panic;
+
+    use super::{add, enable_here, is_enabled, mul, sum, Sum};
+
+    #[test]
+    fn says_what_overflowed_once_enabled() {
+        // nothing else enables checking, so this doesn't race with other tests
+        assert_eq!(add(200u8, 55, "a byte"), 255);
+        assert_eq!(sum([100i8, 27], "a signed byte"), 127);
+        if cfg!(debug_assertions) {
+            // plain arithmetic panics too, just without saying what overflowed
+            assert!(catch_panic(|| add(u8::MAX, 2, "a byte")).is_err());
+        }
+
+        let checking = enable_here();
+        assert_eq!(sum(1..=10u8, "a small triangle"), 55);
+
+        let line = line!() + 1;
+        let message = catch_panic(|| mul(20u8, 13, "the area")).unwrap_err();
+        assert!(message.contains("overflow working out the area: 20 * 13 doesn't fit in u8"), "{message}");
+        assert!(message.contains(&format!("src/checked.rs:{line}:")), "points at the caller: {message}");
+
+        let line = line!() + 1;
+        let message = catch_panic(|| [200u8, 100].into_iter().checked_sum("the total")).unwrap_err();
+        assert!(message.contains(&format!("src/checked.rs:{line}:")), "points at the caller: {message}");
+
+        drop(checking);
+        assert!(!is_enabled());
+    }
+
+    #[test]
+    fn stays_on_the_thread_that_enabled_it() {
+        let _checking = enable_here();
+        assert!(is_enabled());
+        assert!(!std::thread::spawn(is_enabled).join().unwrap());
+
+        // and goes back to how it was, even after a panic
+        catch_panic(|| {
+            let _checking = enable_here();
+            panic!("on purpose");
+        }).unwrap_err();
+        assert!(is_enabled());
+        assert!(catch_panic(|| add(u8::MAX, 1, "a byte")).unwrap_err().contains("overflow working out a byte"));
+    }
+}
@@ -0,0 +1,292 @@
+use std::{num::NonZeroUsize, ops::RangeInclusive, path::PathBuf, str::FromStr, time::Duration};
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+
+use advent_of_code_2024::{client::DEFAULT_BASE_URL, frames::ImageFormat, input::InputSource, report::Format, solution::Part};
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Show what the days are doing on stderr: `-v` for a summary, `-vv` for the working, `-vvv` for every step
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Only show what these days are doing, in full unless `-v` says otherwise
+    #[arg(long, global = true, value_name = "DAYS")]
+    pub trace: Option<DaySelection>,
+
+    /// Fail with an error saying what overflowed, rather than give a wrong answer, if a day's arithmetic overflows
+    #[arg(long, global = true)]
+    pub checked: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the solutions for one or more days
+    Run {
+        /// A single day (`16`), an inclusive range (`3..=9`) or `all`
+        days: DaySelection,
+
+        /// Only run this part of each day
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        /// Read the puzzle input from this file, or `-` for stdin (single day only)
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+
+        /// Run on the examples from the puzzle text, checking their answers
+        #[arg(short, long, conflicts_with = "input")]
+        example: bool,
+
+        /// Run the days in parallel on this many threads, or `0` for one per CPU
+        #[arg(short, long, value_name = "THREADS", conflicts_with = "example")]
+        jobs: Option<usize>,
+
+        /// How to print the results
+        #[arg(short, long, value_enum, default_value_t, conflicts_with = "example")]
+        format: Format,
+
+        /// Count heap allocations, bytes allocated and peak memory for each part
+        #[arg(long, conflicts_with = "example")]
+        allocations: bool,
+    },
+    /// Time parsing and each part of one or more days
+    Bench {
+        /// A single day (`16`), an inclusive range (`3..=9`) or `all`
+        days: DaySelection,
+
+        /// Only time this part of each day
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        /// Read the puzzle input from this file, or `-` for stdin (single day only)
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+
+        /// How many times to repeat each day
+        #[arg(short = 'n', long, default_value_t = NonZeroUsize::new(10).unwrap())]
+        iterations: NonZeroUsize,
+
+        /// Count heap allocations, bytes allocated and peak memory for each stage
+        #[arg(long)]
+        allocations: bool,
+    },
+    /// Check answers against the expected answers file
+    Verify {
+        /// A single day (`16`), an inclusive range (`3..=9`) or `all`
+        #[arg(default_value = "all")]
+        days: DaySelection,
+
+        /// Only check this part of each day
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        /// The expected answers file (defaults to `inputs/answers.txt`)
+        #[arg(short, long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+
+        /// Save answers that aren't in the file yet, instead of reporting them as missing
+        #[arg(short, long)]
+        record: bool,
+    },
+    /// Download puzzle inputs into `inputs/`, skipping any that are already there
+    Fetch {
+        /// A single day (`16`), an inclusive range (`3..=9`) or `all`
+        days: DaySelection,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Solve one part of a day and submit the answer, unless earlier submissions rule it out
+    Submit {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        part: Part,
+
+        /// Submit this instead of the computed answer
+        #[arg(short, long)]
+        answer: Option<String>,
+
+        /// The log of earlier submissions (defaults to `inputs/submissions.txt`)
+        #[arg(short, long, value_name = "PATH")]
+        log: Option<PathBuf>,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Print a random input for a day, for stress testing its solution
+    Generate {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// How many lines or items to make, or the side of the map, depending on the day
+        #[arg(short, long, default_value_t = NonZeroUsize::new(100).unwrap())]
+        size: NonZeroUsize,
+
+        /// Reproduce an earlier input (defaults to a new seed, printed to stderr)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Compare a day's solution with its slow reference implementation on generated inputs
+    CrossCheck {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// How many inputs to try
+        #[arg(short = 'n', long, default_value_t = NonZeroUsize::new(20).unwrap())]
+        cases: NonZeroUsize,
+
+        /// How big to make each input, as for `generate`
+        #[arg(short, long, default_value_t = NonZeroUsize::new(10).unwrap())]
+        size: NonZeroUsize,
+
+        /// The seed of the first input, with the rest counting up from it (defaults to a new seed, printed to stderr)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Play a day's simulation step by step in the terminal, or save it as a sequence of images
+    Animate {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        #[arg(default_value = "1")]
+        part: Part,
+
+        /// Read the puzzle input from this file, or `-` for stdin
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+
+        /// How many frames to show each second
+        #[arg(long, default_value_t = 30.0, value_parser = parse_fps, conflicts_with = "images")]
+        fps: f64,
+
+        /// Only show every this many steps, to speed up long simulations
+        #[arg(long, value_name = "STEPS", default_value_t = NonZeroUsize::new(1).unwrap())]
+        every: NonZeroUsize,
+
+        /// Write numbered images into this directory instead of playing in the terminal
+        #[arg(long, value_name = "DIR")]
+        images: Option<PathBuf>,
+
+        /// The image format to write
+        #[arg(long, value_enum, default_value_t, requires = "images")]
+        format: ImageFormat,
+
+        /// How many pixels wide each tile is in the images
+        #[arg(long, default_value_t = NonZeroUsize::new(4).unwrap(), requires = "images")]
+        scale: NonZeroUsize,
+    },
+    /// Re-run a day whenever its input or example file changes, showing how the answers changed
+    Watch {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// The puzzle input to watch (defaults to `inputs/day_N.txt`)
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// Also run on this example, parsed as the day's examples are
+        #[arg(short, long, value_name = "PATH")]
+        example: Option<PathBuf>,
+
+        /// How often to check the files, in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        interval: u64,
+    },
+    /// Start a new day: write `src/yearYYYY/day_N.rs` from a template, register it and create its input file
+    New {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// The year the day belongs to, which needs a `src/yearYYYY.rs` to register it in
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+    },
+}
+
+/// Where the puzzle site is, which year's puzzles to ask it about, and who to log in as.
+#[derive(Debug, Args)]
+pub struct SiteArgs {
+    /// The year of the puzzles
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    /// The site to talk to, e.g. a local stub for testing
+    #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+
+    /// A file holding the session cookie (defaults to `$AOC_SESSION`, then `~/.config/aoc/session`)
+    #[arg(long, value_name = "PATH")]
+    pub session_file: Option<PathBuf>,
+}
+
+/// The year the solutions are run for, and the one `new`, `fetch` and `submit` use unless told otherwise.
+pub const DEFAULT_YEAR: u16 = 2024;
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+
+#[derive(Debug, Clone)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        Self(FIRST_DAY..=LAST_DAY)
+    }
+
+    pub fn single(day: u8) -> Self {
+        Self(day..=day)
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.0.start() == self.0.end()
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    let day = s.trim().parse::<u8>()
+        .map_err(|_| format!("`{s}` is not a day number"))?;
+
+    (FIRST_DAY..=LAST_DAY).contains(&day)
+        .then_some(day)
+        .ok_or_else(|| format!("day {day} is out of range ({FIRST_DAY}-{LAST_DAY})"))
+}
+
+fn parse_fps(s: &str) -> Result<f64, String> {
+    let fps = s.trim().parse::<f64>()
+        .map_err(|_| format!("`{s}` is not a number"))?;
+
+    // a rate so slow that the delay between frames overflows a `Duration` isn't much use either
+    (fps.is_finite() && fps > 0.0 && Duration::try_from_secs_f64(1.0 / fps).is_ok())
+        .then_some(fps)
+        .ok_or_else(|| format!("`{s}` frames per second is too slow, or not a positive rate"))
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            Ok(Self::all())
+        } else if let Some((start, end)) = s.split_once("..=") {
+            let start = parse_day(start)?;
+            let end = parse_day(end)?;
+            if start > end {
+                return Err(format!("range {start}..={end} is empty"));
+            }
+
+            Ok(Self(start..=end))
+        } else {
+            parse_day(s).map(Self::single)
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::{fmt::Display, io::ErrorKind, path::{Path, PathBuf}, time::Duration};
+
+use crate::solution::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Checked before the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The site asks automated tools to say who they are and where to find them.
+const USER_AGENT: &str = concat!(
+    "github.com/william-gooch/advent-of-code-2024 (",
+    env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"),
+    ", via ureq)",
+);
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+pub fn default_session_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("aoc").join("session"))
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    Io { path: PathBuf, error: std::io::Error },
+    /// 404: the puzzle hasn't unlocked yet
+    NotUnlocked(u8),
+    /// 400: the session cookie is missing, malformed or expired
+    BadSession,
+    Status { code: u16, url: String },
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession => {
+                write!(f, "no session token: set {SESSION_VAR}, or put it in a session file (see `--session-file`)")
+            },
+            ClientError::Io { path, error } => write!(f, "couldn't access {}: {error}", path.display()),
+            ClientError::NotUnlocked(day) => write!(f, "day {day} isn't unlocked yet"),
+            ClientError::BadSession => write!(f, "the server rejected the session token; it may have expired"),
+            ClientError::Status { code, url } => write!(f, "{url} returned HTTP {code}"),
+            ClientError::Transport(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// The session token from `path` if given, else [`SESSION_VAR`], else the default session file.
+pub fn load_session(path: Option<&Path>) -> Result<String, ClientError> {
+    let read = |path: &Path| match std::fs::read_to_string(path) {
+        Ok(session) => Ok(Some(session)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(ClientError::Io { path: path.to_owned(), error }),
+    };
+
+    let session = match path {
+        Some(path) => read(path)?,
+        None => match std::env::var(SESSION_VAR) {
+            Ok(session) => Some(session),
+            Err(_) => default_session_path().map_or(Ok(None), |path| read(&path))?,
+        },
+    };
+
+    session.map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+        .ok_or(ClientError::NoSession)
+}
+
+/// Talks to the puzzle site (or a stand-in for it at `base_url`) about one `year`'s puzzles,
+/// as the owner of `session`.
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, year: u16, session: String) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self { base_url: base_url.trim_end_matches('/').to_owned(), year, session, agent }
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{day}{path}", self.base_url, self.year)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// The body of a successful response, or what went wrong.
+    fn read(day: u8, url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        let response = response.map_err(|err| match err {
+            ureq::Error::Status(404, _) => ClientError::NotUnlocked(day),
+            ureq::Error::Status(400, _) => ClientError::BadSession,
+            ureq::Error::Status(code, _) => ClientError::Status { code, url: url.to_owned() },
+            ureq::Error::Transport(err) => ClientError::Transport(err.to_string()),
+        })?;
+
+        response.into_string()
+            .map_err(|err| ClientError::Transport(format!("couldn't read the response from {url}: {err}")))
+    }
+
+    pub fn download_input(&self, day: u8) -> Result<String, ClientError> {
+        let url = self.url(day, "/input");
+        let response = self.agent.get(&url)
+            .set("Cookie", &self.cookie())
+            .call();
+
+        Self::read(day, &url, response)
+    }
+
+    /// Posts `answer` for `part`, returning the page the site responds with.
+    pub fn post_answer(&self, day: u8, part: Part, answer: &str) -> Result<String, ClientError> {
+        let url = self.url(day, "/answer");
+        let response = self.agent.post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        Self::read(day, &url, response)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input for `day` to `path`, unless it's already there.
+pub fn fetch_input(client: &Client, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.download_input(day)?;
+
+    path.parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(path, input))
+        .map_err(|error| ClientError::Io { path: path.to_owned(), error })?;
+
+    Ok(Fetched::Downloaded)
+}
+
+/// A throwaway HTTP server that answers each request with the next canned response,
+/// keeping the requests it got so tests can check them.
+#[cfg(test)]
+pub mod stub {
+    use std::{io::{BufRead, BufReader, Write}, net::TcpListener, path::PathBuf, thread::JoinHandle};
+
+    pub struct Stub {
+        pub base_url: String,
+        handle: JoinHandle<Vec<String>>,
+    }
+
+    impl Stub {
+        pub fn serve(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind stub server");
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+            let handle = std::thread::spawn(move || {
+                responses.into_iter()
+                    .map(|(code, body)| {
+                        let (mut stream, _) = listener.accept().expect("Stub server didn't get a request");
+                        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                        let mut request = String::new();
+                        let mut content_length = 0;
+                        loop {
+                            let mut line = String::new();
+                            reader.read_line(&mut line).unwrap();
+                            if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                                content_length = len.trim().parse().unwrap();
+                            }
+                            if line.trim().is_empty() { break; }
+                            request.push_str(&line);
+                        }
+
+                        let mut content = vec![0; content_length];
+                        std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                        request.push('\n');
+                        request.push_str(&String::from_utf8(content).unwrap());
+
+                        write!(
+                            stream,
+                            "HTTP/1.1 {code} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                            body.len(),
+                        ).unwrap();
+
+                        request
+                    })
+                    .collect()
+            });
+
+            Self { base_url, handle }
+        }
+
+        /// Every request the server got, once it has answered all of them.
+        pub fn requests(self) -> Vec<String> {
+            self.handle.join().expect("Stub server panicked")
+        }
+    }
+
+    /// A fresh, empty directory for one test to write into.
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-2024-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fetch_input, stub::{temp_dir, Stub}, Client, ClientError, Fetched};
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let stub = Stub::serve(vec![(200, "1 2\n3 4\n")]);
+        let client = Client::new(&stub.base_url, 2024, "abc123".to_owned());
+        let path = temp_dir("fetch-cache").join("inputs").join("day_1.txt");
+
+        assert_eq!(fetch_input(&client, 1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fetch_input(&client, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "), "{}", requests[0]);
+        assert!(requests[0].contains("Cookie: session=abc123"), "{}", requests[0]);
+        assert!(requests[0].contains("User-Agent: github.com/william-gooch/advent-of-code-2024"), "{}", requests[0]);
+    }
+
+    #[test]
+    fn reports_locked_days_and_bad_sessions() {
+        let stub = Stub::serve(vec![(404, "Not Found"), (400, "Bad Request"), (500, "Oops")]);
+        let client = Client::new(&stub.base_url, 2024, "abc123".to_owned());
+        let path = temp_dir("fetch-errors").join("day_25.txt");
+
+        assert!(matches!(fetch_input(&client, 25, &path), Err(ClientError::NotUnlocked(25))));
+        assert!(matches!(fetch_input(&client, 25, &path), Err(ClientError::BadSession)));
+        assert!(matches!(fetch_input(&client, 25, &path), Err(ClientError::Status { code: 500, .. })));
+        assert!(!path.exists());
+
+        stub.requests();
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use crate::{generate::Rng, parse::ParseError, runner::catch_panic, solution::{Answer, Day, Part}};
+
+/// Where a day's solution and its reference implementation first part ways.
+#[derive(Debug)]
+pub enum CrossCheckError {
+    NoGenerator,
+    /// Neither part had a reference answer for any of the generated inputs.
+    NoReference,
+    InvalidInput { seed: u64, error: ParseError },
+    Panicked { seed: u64, part: Part, message: String },
+    Mismatch { seed: u64, part: Part, expected: Answer, actual: Answer },
+}
+
+impl Display for CrossCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrossCheckError::NoGenerator => write!(f, "there's no input generator"),
+            CrossCheckError::NoReference => write!(f, "there's no reference implementation for the generated inputs"),
+            CrossCheckError::InvalidInput { seed, error } => write!(f, "seed {seed}: the generated input doesn't parse: {error}"),
+            CrossCheckError::Panicked { seed, part, message } => write!(f, "seed {seed}, part {part}: {message}"),
+            CrossCheckError::Mismatch { seed, part, expected, actual } => {
+                write!(f, "seed {seed}, part {part}: the reference gave {expected} but the solution gave {actual}")
+            },
+        }
+    }
+}
+
+impl std::error::Error for CrossCheckError {}
+
+impl CrossCheckError {
+    /// The seed of the input that went wrong, to pass to `generate`.
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            CrossCheckError::NoGenerator | CrossCheckError::NoReference => None,
+            CrossCheckError::InvalidInput { seed, .. }
+            | CrossCheckError::Panicked { seed, .. }
+            | CrossCheckError::Mismatch { seed, .. } => Some(*seed),
+        }
+    }
+}
+
+/// Solves an input generated from each of `seeds` both ways, stopping at the first one where the
+/// solution and the reference disagree. Returns how many answers were compared.
+pub fn cross_check(day: &Day, seeds: impl IntoIterator<Item = u64>, size: usize) -> Result<usize, CrossCheckError> {
+    let mut compared = 0;
+
+    for seed in seeds {
+        let input = day.generate(&mut Rng::new(seed), size)
+            .ok_or(CrossCheckError::NoGenerator)?;
+        let parsed = day.parse(&input)
+            .map_err(|error| CrossCheckError::InvalidInput { seed, error })?;
+
+        for part in Part::ALL {
+            let panicked = |message| CrossCheckError::Panicked { seed, part, message };
+
+            // only run the solution where there's something to compare it to
+            let Some(expected) = catch_panic(|| day.reference(part, &parsed)).map_err(panicked)? else { continue };
+            let Some(actual) = catch_panic(|| day.solve(part, &parsed)).map_err(panicked)? else { continue };
+
+            if actual != expected {
+                return Err(CrossCheckError::Mismatch { seed, part, expected, actual });
+            }
+            compared += 1;
+        }
+    }
+
+    if compared == 0 {
+        return Err(CrossCheckError::NoReference);
+    }
+
+    Ok(compared)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::Rng, parse::{self, ParseError}, solution::{Answer, Day, Part, Solution}, year2024::day_1::Day1};
+
+    use super::{cross_check, CrossCheckError};
+
+    /// Doubles a number, except that part 2 gets odd numbers wrong.
+    struct Doubling;
+
+    impl Solution for Doubling {
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse::number(input)
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+            Some(rng.below(size as u64).to_string())
+        }
+
+        fn part_1(n: &Self::Input) -> Answer {
+            (n * 2).into()
+        }
+
+        fn part_2(n: &Self::Input) -> Answer {
+            (n * 2 + n % 2).into()
+        }
+
+        fn reference(_part: Part, n: &Self::Input) -> Option<Answer> {
+            Some((n + n).into())
+        }
+    }
+
+    #[test]
+    fn stops_at_the_first_disagreement() {
+        let day = Day::new::<Doubling>(1);
+        let odd_seed = (0..).find(|&seed| Rng::new(seed).below(100) % 2 == 1).unwrap();
+
+        assert_eq!(cross_check(&day, 0..odd_seed, 100).unwrap(), 2 * odd_seed as usize);
+        match cross_check(&day, 0.., 100) {
+            Err(CrossCheckError::Mismatch { seed, part: Part::Two, expected, actual }) => {
+                assert_eq!(seed, odd_seed);
+                assert_eq!(actual, Answer::from(expected.to_string().parse::<u64>().unwrap() + 1));
+            },
+            other => panic!("{other:?}"),
+        }
+
+        // without any references there's nothing to check
+        assert!(matches!(cross_check(&Day::new::<Day1>(1), 0..3, 10), Err(CrossCheckError::NoReference)));
+    }
+}
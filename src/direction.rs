@@ -0,0 +1,254 @@
+use nalgebra::Vector2;
+use ndarray::Ix2;
+
+/// One of the four orthogonal directions on a map, where up is towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// `[d_row, d_col]`
+    pub fn delta(self) -> [isize; 2] {
+        match self {
+            Direction::Up => [-1, 0],
+            Direction::Right => [0, 1],
+            Direction::Down => [1, 0],
+            Direction::Left => [0, -1],
+        }
+    }
+
+    /// The next position in this direction, or `None` if that would go above row 0 or left of column 0.
+    pub fn offset(self, pos: Ix2) -> Option<Ix2> {
+        offset(pos, self.delta())
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Direction::Up),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            c => Err(c),
+        }
+    }
+}
+
+impl From<Direction> for char {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// The four orthogonal directions plus the four diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&d| d == self).expect("Every direction is in ALL")
+    }
+
+    /// Turns clockwise by 45°.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns anticlockwise by 45°.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// `[d_row, d_col]`
+    pub fn delta(self) -> [isize; 2] {
+        match self {
+            Direction8::Up => [-1, 0],
+            Direction8::UpRight => [-1, 1],
+            Direction8::Right => [0, 1],
+            Direction8::DownRight => [1, 1],
+            Direction8::Down => [1, 0],
+            Direction8::DownLeft => [1, -1],
+            Direction8::Left => [0, -1],
+            Direction8::UpLeft => [-1, -1],
+        }
+    }
+
+    /// The next position in this direction, or `None` if that would go above row 0 or left of column 0.
+    pub fn offset(self, pos: Ix2) -> Option<Ix2> {
+        offset(pos, self.delta())
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+/// Moves `pos` by `[d_row, d_col]`, or `None` if that would go above row 0 or left of column 0.
+pub fn offset(pos: Ix2, [d_row, d_col]: [isize; 2]) -> Option<Ix2> {
+    Some(Ix2(pos[0].checked_add_signed(d_row)?, pos[1].checked_add_signed(d_col)?))
+}
+
+/// Conversions to and from the `(row, col)` positions used to index maps.
+///
+/// Note that `Vector2`s are `(x, y)`, so they're swapped relative to `Ix2`.
+pub trait ToIx2 {
+    /// `None` if either coordinate is negative or doesn't fit.
+    fn to_ix2(self) -> Option<Ix2>;
+}
+
+impl ToIx2 for (usize, usize) {
+    fn to_ix2(self) -> Option<Ix2> {
+        Some(Ix2(self.0, self.1))
+    }
+}
+
+impl ToIx2 for (isize, isize) {
+    fn to_ix2(self) -> Option<Ix2> {
+        Some(Ix2(self.0.try_into().ok()?, self.1.try_into().ok()?))
+    }
+}
+
+macro_rules! vector_to_ix2 {
+    ($($t:ty),*) => {
+        $(
+            impl ToIx2 for Vector2<$t> {
+                fn to_ix2(self) -> Option<Ix2> {
+                    Some(Ix2(self.y.try_into().ok()?, self.x.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+vector_to_ix2!(u32, i32, u64, i64, usize, isize);
+
+pub fn ix2_to_signed(pos: Ix2) -> (isize, isize) {
+    (pos[0] as isize, pos[1] as isize)
+}
+
+pub fn ix2_to_vector(pos: Ix2) -> Vector2<usize> {
+    Vector2::new(pos[1], pos[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector2;
+    use ndarray::Ix2;
+
+    use super::{Direction, Direction8, ToIx2};
+
+    #[test]
+    fn rotations() {
+        Direction::ALL.into_iter()
+            .for_each(|d| {
+                assert_eq!(d.turn_right().turn_left(), d);
+                assert_eq!(d.turn_right().turn_right(), d.opposite());
+                assert_eq!(Direction8::from(d).opposite(), Direction8::from(d.opposite()));
+            });
+
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+    }
+
+    #[test]
+    fn parses_arrows() {
+        assert_eq!("^>v<".chars().map(Direction::try_from).collect::<Result<Vec<_>, _>>(), Ok(Direction::ALL.to_vec()));
+        assert_eq!(Direction::try_from('x'), Err('x'));
+    }
+
+    #[test]
+    fn offsets_are_checked() {
+        assert_eq!(Direction::Up.offset(Ix2(0, 3)), None);
+        assert_eq!(Direction::Left.offset(Ix2(3, 0)), None);
+        assert_eq!(Direction8::DownRight.offset(Ix2(3, 0)), Some(Ix2(4, 1)));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Vector2::new(3_u32, 5).to_ix2(), Some(Ix2(5, 3)));
+        assert_eq!((-1_isize, 2_isize).to_ix2(), None);
+        assert_eq!(super::ix2_to_vector(Ix2(5, 3)), Vector2::new(3, 5));
+    }
+}
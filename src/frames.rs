@@ -0,0 +1,268 @@
+use std::{fs::File, io::{self, BufWriter, Write}, path::PathBuf, thread, time::Duration};
+
+use clap::ValueEnum;
+
+use crate::grid::Grid;
+
+/// A snapshot of a simulation, one character per tile, drawn as the puzzle text would draw it.
+pub type Frame = Grid<char>;
+
+/// Somewhere for a simulation to push its frames as it runs.
+pub trait FrameSink {
+    fn push(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called once the simulation has pushed its last frame.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl FrameSink for Vec<Frame> {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        self.push(frame.clone());
+        Ok(())
+    }
+}
+
+impl<S: FrameSink + ?Sized> FrameSink for Box<S> {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        (**self).push(frame)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        (**self).finish()
+    }
+}
+
+/// The colour a tile is drawn in, on the terminal and in images.
+pub fn colour(tile: char) -> [u8; 3] {
+    match tile {
+        '.' | ' ' => [20, 20, 30],
+        '#' => [100, 100, 120],
+        'O' | '[' | ']' => [190, 130, 60],
+        '^' | '>' | 'v' | '<' | '@' => [250, 210, 50],
+        'X' => [50, 100, 180],
+        'S' | 'E' => [220, 60, 60],
+        '1'..='9' => [80, 200, 80],
+        _ => [230, 230, 230],
+    }
+}
+
+/// Plays frames back in a terminal that understands ANSI escapes, redrawing each one over the last.
+pub struct Terminal<W> {
+    out: W,
+    delay: Duration,
+    cleared: bool,
+}
+
+impl Terminal<io::Stdout> {
+    /// `frames_per_second` has to be positive and not so small that the delay overflows, as `--fps` checks.
+    pub fn stdout(frames_per_second: f64) -> Self {
+        Self::new(io::stdout(), Duration::from_secs_f64(1.0 / frames_per_second))
+    }
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self { out, delay, cleared: false }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> FrameSink for Terminal<W> {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        // clear the screen once, then just move back to the top left
+        let mut ansi = if self.cleared { "\x1b[H" } else { "\x1b[2J\x1b[H" }.to_owned();
+        self.cleared = true;
+
+        let mut current = None;
+        frame.indexed_iter()
+            .for_each(|(pos, &tile)| {
+                if pos[1] == 0 && pos[0] > 0 {
+                    ansi.push('\n');
+                }
+                // only change colour when it's different from the last tile's
+                let [r, g, b] = colour(tile);
+                if current.replace([r, g, b]) != Some([r, g, b]) {
+                    ansi += &format!("\x1b[38;2;{r};{g};{b}m");
+                }
+                ansi.push(tile);
+            });
+        // and clear what's left below, in case the last frame was taller
+        ansi += "\x1b[0m\n\x1b[J";
+
+        self.out.write_all(ansi.as_bytes())?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ImageFormat {
+    /// Colour
+    #[default]
+    Ppm,
+    /// Greyscale
+    Pgm,
+}
+
+impl ImageFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Pgm => "pgm",
+        }
+    }
+}
+
+/// Writes `frame` as a binary PPM or PGM image, with each tile drawn as a `scale` × `scale` square.
+pub fn encode(frame: &Frame, format: ImageFormat, scale: usize, mut out: impl Write) -> io::Result<()> {
+    let magic = match format {
+        ImageFormat::Ppm => "P6",
+        ImageFormat::Pgm => "P5",
+    };
+    write!(out, "{magic}\n{} {}\n255\n", frame.cols() * scale, frame.rows() * scale)?;
+
+    let tiles = frame.iter().collect::<Vec<_>>();
+    tiles.chunks(frame.cols())
+        .try_for_each(|row| {
+            let pixels = row.iter()
+                .flat_map(|&&tile| {
+                    let [r, g, b] = colour(tile);
+                    let pixel = match format {
+                        ImageFormat::Ppm => vec![r, g, b],
+                        // the usual luma weights
+                        ImageFormat::Pgm => vec![((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8],
+                    };
+                    pixel.repeat(scale)
+                })
+                .collect::<Vec<_>>();
+
+            (0..scale).try_for_each(|_| out.write_all(&pixels))
+        })?;
+
+    out.flush()
+}
+
+/// Writes each frame to its own numbered image in `dir`: `frame_00000.ppm`, `frame_00001.ppm`, ...
+pub struct Images {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    written: usize,
+}
+
+impl Images {
+    pub fn new(dir: PathBuf, format: ImageFormat, scale: usize) -> io::Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir, format, scale, written: 0 })
+    }
+}
+
+impl FrameSink for Images {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self.dir.join(format!("frame_{:05}.{}", self.written, self.format.extension()));
+        encode(frame, self.format, self.scale, BufWriter::new(File::create(path)?))?;
+        self.written += 1;
+
+        Ok(())
+    }
+}
+
+/// Passes on only every `n`th frame, and the last one, to speed up long simulations.
+pub struct EveryNth<S> {
+    sink: S,
+    n: usize,
+    seen: usize,
+    skipped: Option<Frame>,
+}
+
+impl<S: FrameSink> EveryNth<S> {
+    pub fn new(sink: S, n: usize) -> Self {
+        Self { sink, n, seen: 0, skipped: None }
+    }
+}
+
+impl<S: FrameSink> FrameSink for EveryNth<S> {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        self.seen += 1;
+
+        if (self.seen - 1).is_multiple_of(self.n) {
+            self.skipped = None;
+            self.sink.push(frame)
+        } else {
+            self.skipped = Some(frame.clone());
+            Ok(())
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if let Some(last) = self.skipped.take() {
+            self.sink.push(&last)?;
+        }
+
+        self.sink.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::grid::Grid;
+
+    use super::{colour, encode, EveryNth, Frame, FrameSink, ImageFormat, Terminal};
+
+    fn frame(text: &str) -> Frame {
+        Grid::parse(text, Some).unwrap()
+    }
+
+    #[test]
+    fn encodes_images() {
+        let mut ppm = Vec::new();
+        encode(&frame("#.\n.@\n"), ImageFormat::Ppm, 2, &mut ppm).unwrap();
+
+        let (header, pixels) = ppm.split_at(11);
+        assert_eq!(header, b"P6\n4 4\n255\n");
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(pixels[..6], [colour('#'), colour('#')].concat());
+        assert_eq!(pixels[3 * 12 + 6..], [colour('@'), colour('@')].concat());
+
+        let mut pgm = Vec::new();
+        encode(&frame("#.\n"), ImageFormat::Pgm, 1, &mut pgm).unwrap();
+        assert_eq!(pgm, b"P5\n2 1\n255\n\x66\x15");
+    }
+
+    #[test]
+    fn keeps_every_nth_and_the_last_frame() {
+        let frames = ["0", "1", "2", "3", "4", "5"].map(frame);
+        let mut sink = EveryNth::new(Vec::new(), 2);
+        frames.iter().try_for_each(|f| sink.push(f)).unwrap();
+        sink.finish().unwrap();
+
+        assert_eq!(sink.sink.iter().map(ToString::to_string).collect::<Vec<_>>(), ["0\n", "2\n", "4\n", "5\n"]);
+    }
+
+    #[test]
+    fn redraws_in_place() {
+        let mut terminal = Terminal::new(Vec::new(), Duration::ZERO);
+        terminal.push(&frame("##\n#.\n")).unwrap();
+        terminal.push(&frame("..\n")).unwrap();
+
+        let out = String::from_utf8(terminal.into_inner()).unwrap();
+        let [r, g, b] = colour('#');
+        let wall = format!("\x1b[38;2;{r};{g};{b}m");
+        assert!(out.starts_with(&format!("\x1b[2J\x1b[H{wall}##\n#")), "{out:?}");
+        assert_eq!(out.matches("\x1b[2J").count(), 1);
+        assert_eq!(out.matches(&wall).count(), 1);
+
+        // the second frame is a row shorter, so the first's last row is cleared after it
+        let (_, last) = out.rsplit_once("\x1b[H").unwrap();
+        assert!(last.ends_with("..\x1b[0m\n\x1b[J"), "{last:?}");
+    }
+}
@@ -0,0 +1,124 @@
+use std::ops::RangeInclusive;
+
+use ndarray::Ix2;
+
+use crate::grid::Grid;
+
+/// A small seedable random number generator (SplitMix64), so that a generated input
+/// can always be reproduced from its seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let z = self.0;
+        let z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Can't pick from an empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Uniform in `range`, inclusive of both ends.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        low + self.below(high.abs_diff(low) + 1) as i64
+    }
+
+    /// A uniform index into something `len` long.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Fisher-Yates.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        (1..items.len()).rev()
+            .for_each(|i| items.swap(i, self.index(i + 1)));
+    }
+}
+
+/// A perfect maze of `rows` × `cols` cells, with exactly one path between any two of them.
+///
+/// Walls are `true`. Cells sit at odd positions of a `2 * rows + 1` × `2 * cols + 1` grid,
+/// so the maze is always surrounded by wall.
+pub fn maze(rng: &mut Rng, rows: usize, cols: usize) -> Grid<bool> {
+    let mut walls = Grid::filled(2 * rows + 1, 2 * cols + 1, true);
+    let cell = |row: usize, col: usize| Ix2(2 * row + 1, 2 * col + 1);
+
+    // depth-first, backtracking when every neighbour has been carved
+    let mut stack = vec![(rng.index(rows), rng.index(cols))];
+    walls[cell(stack[0].0, stack[0].1)] = false;
+
+    while let Some(&(row, col)) = stack.last() {
+        let unvisited = [(-1, 0), (0, 1), (1, 0), (0, -1)].into_iter()
+            .filter_map(|(d_row, d_col)| {
+                let next = (row.checked_add_signed(d_row)?, col.checked_add_signed(d_col)?);
+                (next.0 < rows && next.1 < cols && walls[cell(next.0, next.1)]).then_some(next)
+            })
+            .collect::<Vec<_>>();
+
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = *rng.choose(&unvisited);
+        walls[Ix2(row + next.0 + 1, col + next.1 + 1)] = false;
+        walls[cell(next.0, next.1)] = false;
+        stack.push(next);
+    }
+
+    walls
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Ix2;
+
+    use crate::search;
+
+    use super::{maze, Rng};
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(2024);
+        let mut b = Rng::new(2024);
+
+        let a = (0..100).map(|_| a.range(-3..=3)).collect::<Vec<_>>();
+        let b = (0..100).map(|_| b.range(-3..=3)).collect::<Vec<_>>();
+
+        assert_eq!(a, b);
+        assert!(a.iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| a.contains(&n)));
+    }
+
+    #[test]
+    fn mazes_are_perfect() {
+        let walls = maze(&mut Rng::new(7), 6, 9);
+        let open = walls.iter().filter(|&&wall| !wall).count();
+        let search = search::bfs([Ix2(1, 1)], |pos| walls.neighbours_4(pos).filter(|&next| !walls[next]));
+
+        // connected, and a tree: every cell but the first is reached through exactly one passage
+        assert_eq!((walls.rows(), walls.cols()), (13, 19));
+        assert_eq!(search.distances().len(), open);
+        assert_eq!(open, 6 * 9 + (6 * 9 - 1));
+    }
+}
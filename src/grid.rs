@@ -0,0 +1,270 @@
+use std::{fmt::Display, ops::{Index, IndexMut}};
+
+use ndarray::{Array2, Ix2};
+
+use crate::{direction::{self, Direction, Direction8}, parse::{self, ParseError, ParseErrorKind}};
+
+/// `(row, col)`, the same as indexing into an `Array2`.
+pub type Pos = Ix2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged { expected: usize, found: usize },
+    InvalidChar(char),
+    MissingMarker(char),
+    DuplicateMarker(char),
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the map is empty"),
+            GridError::Ragged { expected, found } => {
+                write!(f, "this row is {found} wide, but the rows before it are {expected} wide")
+            },
+            GridError::InvalidChar(c) => write!(f, "unexpected `{c}`"),
+            GridError::MissingMarker(c) => write!(f, "the map has no `{c}`"),
+            GridError::DuplicateMarker(c) => write!(f, "the map has more than one `{c}`"),
+        }
+    }
+}
+
+/// A rectangular map of tiles, usually parsed from the puzzle's character map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one tile per character, with `tile` returning `None` for characters that aren't allowed.
+    pub fn parse(input: &str, tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_with_markers(input, [], tile).map(|(grid, [])| grid)
+    }
+
+    /// Like [`Grid::parse`], but also finds the single position of each marker (e.g. `S` and `E`).
+    /// The markers are still passed to `tile`, to decide what lies underneath them.
+    pub fn parse_with_markers<const N: usize>(
+        input: &str,
+        markers: [char; N],
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, [Pos; N]), ParseError> {
+        let error = |text: &str, err| ParseError::new(text, ParseErrorKind::Grid(err));
+
+        let mut found: [Option<Pos>; N] = [None; N];
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let start = cells.len();
+
+            for (col, (i, c)) in line.char_indices().enumerate() {
+                let text = &line[i..i + c.len_utf8()];
+
+                if let Some(m) = markers.iter().position(|&m| m == c) {
+                    if found[m].replace(Ix2(row, col)).is_some() {
+                        return Err(error(text, GridError::DuplicateMarker(c)));
+                    }
+                }
+
+                cells.push(tile(c).ok_or_else(|| error(text, GridError::InvalidChar(c)))?);
+            }
+
+            let found_width = cells.len() - start;
+            let expected = *width.get_or_insert(found_width);
+            if found_width != expected {
+                return Err(error(line, GridError::Ragged { expected, found: found_width }));
+            }
+
+            height += 1;
+        }
+
+        let width = width.filter(|&w| w > 0).ok_or_else(|| error(input, GridError::Empty))?;
+
+        let mut positions = [Ix2(0, 0); N];
+        for i in 0..N {
+            positions[i] = found[i].ok_or_else(|| error(parse::end_of(input), GridError::MissingMarker(markers[i])))?;
+        }
+
+        let cells = Array2::from_shape_vec((height, width), cells)
+            .expect("Every row was checked to be the same width");
+
+        Ok((Self { cells }, positions))
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        Self { cells: Array2::from_shape_fn((rows, cols), |(row, col)| f(Ix2(row, col))) }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos[0] < self.rows() && pos[1] < self.cols()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    /// Moves `pos` by `[d_row, d_col]`, if that stays on the grid.
+    pub fn offset(&self, pos: Pos, delta: [isize; 2]) -> Option<Pos> {
+        direction::offset(pos, delta).filter(|&pos| self.contains(pos))
+    }
+
+    /// Moves `pos` one step in `direction`, if that stays on the grid.
+    pub fn step(&self, pos: Pos, direction: impl Into<Direction8>) -> Option<Pos> {
+        self.offset(pos, direction.into().delta())
+    }
+
+    /// The up to 4 orthogonally adjacent positions that are on the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to 8 orthogonally or diagonally adjacent positions that are on the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every position and its tile, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.indexed_iter().map(|((row, col), t)| (Ix2(row, col), t))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.map(f) }
+    }
+
+    /// Draws each tile as the character returned by `f`, e.g. to overlay a robot on the map.
+    pub fn display_with<F: Fn(Pos, &T) -> char>(&self, f: F) -> impl Display + use<'_, T, F> {
+        DisplayWith { grid: self, f }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
+        Self { cells: Array2::from_elem((rows, cols), value) }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self.cells[pos]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self.cells[pos]
+    }
+}
+
+struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, F: Fn(Pos, &T) -> char> Display for DisplayWith<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.grid.cells.rows().into_iter()
+            .enumerate()
+            .try_for_each(|(row, r)| {
+                r.iter()
+                    .enumerate()
+                    .try_for_each(|(col, t)| write!(f, "{}", (self.f)(Ix2(row, col), t)))?;
+                writeln!(f)
+            })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cells.rows().into_iter()
+            .try_for_each(|r| {
+                r.iter().try_for_each(|t| write!(f, "{t}"))?;
+                writeln!(f)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Ix2;
+
+    use crate::parse::ParseErrorKind;
+
+    use super::{Grid, GridError};
+
+    fn wall(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' | 'S' | 'E' => Some(false),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parses_markers() {
+        let (grid, [start, end]) = Grid::parse_with_markers("#S.\n.E#\n", ['S', 'E'], wall).unwrap();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!((start, end), (Ix2(0, 1), Ix2(1, 1)));
+        assert!(grid[Ix2(1, 2)]);
+        assert_eq!(grid.get(Ix2(2, 0)), None);
+    }
+
+    #[test]
+    fn rejects_bad_maps() {
+        let error = |input: &str, markers: &[char]| {
+            let err = match *markers {
+                [] => Grid::parse(input, wall).unwrap_err(),
+                [m] => Grid::parse_with_markers(input, [m], wall).unwrap_err(),
+                _ => unreachable!(),
+            }.in_day(0, input);
+
+            let ParseErrorKind::Grid(kind) = err.kind else { panic!("not a grid error: {err}") };
+            let location = err.location.expect("error should be within the input");
+            (kind, location.line, location.column)
+        };
+
+        assert_eq!(error("", &[]), (GridError::Empty, 1, 1));
+        assert_eq!(error("##\n#\n", &[]), (GridError::Ragged { expected: 2, found: 1 }, 2, 1));
+        assert_eq!(error("#x\n", &[]), (GridError::InvalidChar('x'), 1, 2));
+        assert_eq!(error("#.\n", &['S']), (GridError::MissingMarker('S'), 2, 1));
+        assert_eq!(error("SS\n", &['S']), (GridError::DuplicateMarker('S'), 1, 2));
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = Grid::filled(3, 3, ());
+
+        assert_eq!(grid.neighbours_4(Ix2(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours_8(Ix2(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_4(Ix2(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8(Ix2(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn displays_rows() {
+        let grid = Grid::parse("#.\n.#\n", wall).unwrap();
+
+        assert_eq!(grid.display_with(|_, &w| if w { '#' } else { '.' }).to_string(), "#.\n.#\n");
+    }
+}
@@ -0,0 +1,63 @@
+use std::{fmt::Display, io::Read, path::PathBuf, str::FromStr};
+
+pub const INPUT_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, Default)]
+pub enum InputSource {
+    /// `inputs/day_N.txt`, relative to the working directory
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("input path is empty".to_owned()),
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::Path(path.into())),
+        }
+    }
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    [INPUT_DIR, &format!("day_{day}.txt")].iter().collect()
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub source: String,
+    pub error: std::io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {}: couldn't read input from {}: {}", self.day, self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn load(day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Stdin => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|error| InputError { day, source: "stdin".to_owned(), error })
+        },
+        InputSource::Default | InputSource::Path(_) => {
+            let path = match source {
+                InputSource::Path(path) => path.clone(),
+                _ => default_path(day),
+            };
+
+            std::fs::read_to_string(&path)
+                .map_err(|error| InputError { day, source: path.display().to_string(), error })
+        },
+    }
+}
@@ -0,0 +1,31 @@
+#![recursion_limit = "256"]
+#![feature(iterator_try_collect)]
+#![feature(iter_collect_into)]
+#![feature(iter_next_chunk)]
+#![feature(str_as_str)]
+
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod checked;
+pub mod client;
+pub mod cross_check;
+pub mod direction;
+pub mod frames;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod memo;
+pub mod num;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod trace;
+pub mod watch;
+
+pub mod year2024;
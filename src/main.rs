@@ -1,63 +1,581 @@
-#![recursion_limit = "256"]
-#![feature(iterator_try_collect)]
-#![feature(iter_collect_into)]
-#![feature(iter_map_windows)]
-#![feature(iter_next_chunk)]
-#![feature(iter_array_chunks)]
-#![feature(str_as_str)]
-#![feature(unsigned_signed_diff)]
-#![allow(unused)]
-
-mod day_1;
-mod day_2;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
+mod cli;
+
+use std::{num::NonZeroUsize, path::{Path, PathBuf}, time::{Duration, SystemTime}};
+
+use clap::{error::ErrorKind, CommandFactory, Parser};
+use advent_of_code_2024::{
+    allocations::{self, CountingAllocator},
+    answers::{self, Answers, Status, Verified},
+    bench,
+    checked,
+    client::{self, Client, Fetched},
+    cross_check,
+    frames::{EveryNth, FrameSink, ImageFormat, Images, Terminal},
+    generate::Rng,
+    input::{self, InputSource},
+    registry::{self, Year},
+    report::{self, Format},
+    runner::{self, DayResult, Outcome},
+    scaffold,
+    solution::{Answer, Day, Part},
+    submit::{self, SubmissionLog, Verdict},
+    trace::{self, Level},
+    watch::{self, Watcher},
+};
+use cli::{Cli, Command, DaySelection, SiteArgs, DEFAULT_YEAR, FIRST_DAY, LAST_DAY};
+
+/// Counts nothing until `--allocations` turns it on.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The puzzles this binary runs.
+fn year() -> &'static Year {
+    registry::year(DEFAULT_YEAR).expect("The year is registered")
+}
+
+fn select_days(days: &DaySelection, part: Option<Part>, input: &Option<InputSource>) -> Vec<&'static Day> {
+    if input.is_some() && !days.is_single() {
+        Cli::command()
+            .error(ErrorKind::ArgumentConflict, "--input can only be used when running a single day")
+            .exit();
+    }
+
+    let selected = year().days.iter()
+        .filter(|day| days.contains(day.day))
+        .filter(|day| part.is_none_or(|part| day.has_part(part)))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        let part = part.expect("every day has a part 1");
+        Cli::command()
+            .error(ErrorKind::InvalidValue, format!("no selected day has a part {part}"))
+            .exit();
+    }
+
+    selected
+}
+
+fn selected_parts(day: &Day, part: Option<Part>) -> Vec<Part> {
+    Part::ALL.into_iter()
+        .filter(|p| part.is_none_or(|part| part == *p))
+        .filter(|&p| day.has_part(p))
+        .collect()
+}
+
+fn run(days: DaySelection, part: Option<Part>, input: Option<InputSource>, jobs: Option<usize>, format: Format) -> bool {
+    let selected = select_days(&days, part, &input)
+        .into_iter()
+        .map(|day| (day, selected_parts(day, part)))
+        .collect::<Vec<_>>();
+    let input = input.unwrap_or_default();
+
+    let print = |result: &DayResult| {
+        if format == Format::Text {
+            result.print();
+        }
+    };
+
+    let results = match jobs {
+        Some(threads) => {
+            let results = runner::run_parallel(&selected, &input, threads);
+            results.iter().for_each(print);
+            results
+        },
+        None => selected.iter()
+            .map(|(day, parts)| runner::run_day(day, &input, parts))
+            .inspect(print)
+            .collect::<Vec<_>>(),
+    };
+
+    let records = || results.iter()
+        .zip(&selected)
+        .flat_map(|(result, (_, parts))| report::records(result, parts))
+        .collect::<Vec<_>>();
+
+    let written = match format {
+        Format::Text => Ok(()),
+        Format::Json => report::write_json(&records(), std::io::stdout().lock())
+            .map(|_| println!())
+            .map_err(|err| err.to_string()),
+        Format::Csv => report::write_csv(&records(), std::io::stdout().lock())
+            .map_err(|err| err.to_string()),
+    };
+
+    if let Err(err) = written {
+        eprintln!("couldn't write results: {err}");
+        return false;
+    }
+
+    results.iter().all(DayResult::is_ok)
+}
+
+fn run_examples(days: DaySelection, part: Option<Part>) -> bool {
+    select_days(&days, part, &None).into_iter()
+        .map(|day| {
+            if day.examples.is_empty() {
+                eprintln!("day {}: no examples", day.day);
+                return true;
+            }
+
+            day.examples.iter()
+                .enumerate()
+                .map(|(i, example)| {
+                    println!("--- Day {} (example {}) ---", day.day, i + 1);
+
+                    let parsed = match day.parse_example(example.input) {
+                        Ok(parsed) => parsed,
+                        Err(err) => {
+                            eprintln!("{err}");
+                            return false;
+                        },
+                    };
+
+                    selected_parts(day, part).into_iter()
+                        .filter_map(|p| Some((p, example.expected(p)?)))
+                        .filter_map(|(p, expected)| Some((p, expected, day.solve(p, &parsed)?)))
+                        .map(|(p, expected, answer)| {
+                            if answer.to_string() == expected {
+                                println!("Part {p}: {answer}");
+                                true
+                            } else {
+                                println!("Part {p}: {answer} (expected {expected})");
+                                false
+                            }
+                        })
+                        .filter(|ok| !ok)
+                        .count() == 0
+                })
+                .filter(|ok| !ok)
+                .count() == 0
+        })
+        .filter(|ok| !ok)
+        .count() == 0
+}
+
+fn bench(days: DaySelection, part: Option<Part>, input: Option<InputSource>, iterations: NonZeroUsize) -> bool {
+    let selected = select_days(&days, part, &input);
+    let input = input.unwrap_or_default();
+
+    let mut ok = true;
+    let benches = selected.into_iter()
+        .filter_map(|day| {
+            let puzzle_input = input::load(day.day, &input)
+                .inspect_err(|err| {
+                    eprintln!("{err}");
+                    ok = false;
+                })
+                .ok()?;
+
+            eprintln!("Benchmarking day {}...", day.day);
+            bench::bench_day(day, &puzzle_input, &selected_parts(day, part), iterations.get())
+                .inspect_err(|err| {
+                    eprintln!("{err}");
+                    ok = false;
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
+    bench::print_report(&benches);
+
+    ok
+}
+
+fn verify(days: DaySelection, part: Option<Part>, answers_path: Option<PathBuf>, record: bool) -> bool {
+    let selected = select_days(&days, part, &None);
+    let answers_path = answers_path.unwrap_or_else(answers::default_path);
+
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        },
+    };
+
+    let mut ok = true;
+    let results = selected.into_iter()
+        .filter_map(|day| {
+            let puzzle_input = input::load(day.day, &InputSource::Default)
+                .inspect_err(|err| {
+                    eprintln!("{err}");
+                    ok = false;
+                })
+                .ok()?;
+
+            let parsed = day.parse(&puzzle_input)
+                .inspect_err(|err| {
+                    eprintln!("{err}");
+                    ok = false;
+                })
+                .ok()?;
+
+            Some(selected_parts(day, part).into_iter()
+                .filter_map(|p| {
+                    let answer = day.solve(p, &parsed)?;
+                    let status = match Status::check(&answers, day.day, p, &answer) {
+                        Status::Missing if record && answer != Answer::None => {
+                            answers.insert(day.day, p, &answer);
+                            Status::Recorded
+                        },
+                        status => status,
+                    };
+
+                    Some(Verified { day: day.day, part: p, answer, status })
+                })
+                .collect::<Vec<_>>())
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    answers::print_summary(&results);
+
+    let recorded = results.iter().any(|r| r.status == Status::Recorded);
+    if recorded {
+        if let Err(err) = answers.save(&answers_path) {
+            eprintln!("{err}");
+            ok = false;
+        }
+    }
+
+    ok && results.iter().all(|r| !matches!(r.status, Status::Fail { .. }))
+}
+
+fn fetch(days: DaySelection, site: SiteArgs) -> bool {
+    let session = match client::load_session(site.session_file.as_deref()) {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        },
+    };
+    let client = Client::new(&site.base_url, site.year, session);
+
+    // a day's input can be fetched before it has a solution, or for a year without any
+    (FIRST_DAY..=LAST_DAY)
+        .filter(|&day| days.contains(day))
+        .map(|day| {
+            let path = input::default_path(day);
+
+            match client::fetch_input(&client, day, &path) {
+                Ok(Fetched::Cached) => println!("day {day}: already have {}", path.display()),
+                Ok(Fetched::Downloaded) => println!("day {day}: saved to {}", path.display()),
+                Err(err) => {
+                    eprintln!("day {day}: {err}");
+                    return false;
+                },
+            }
+
+            true
+        })
+        .filter(|ok| !ok)
+        .count() == 0
+}
+
+fn submit(day: u8, part: Part, answer: Option<String>, log_path: Option<PathBuf>, site: SiteArgs) -> bool {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let Some(day) = registry::day(site.year, day).filter(|day| day.has_part(part)) else {
+                eprintln!("{} day {day} part {part} isn't solved here, so pass the answer to submit with --answer", site.year);
+                return false;
+            };
+            let result = runner::run_day(day, &InputSource::Default, &[part]);
+
+            match &result.outcome {
+                Outcome::Solved { parts, .. } => match &parts[0].answer {
+                    Ok(Answer::None) => {
+                        eprintln!("Day {} part {part} has no answer to submit", day.day);
+                        return false;
+                    },
+                    Ok(answer) => answer.to_string(),
+                    Err(_) => {
+                        result.print();
+                        return false;
+                    },
+                },
+                _ => {
+                    result.print();
+                    return false;
+                },
+            }
+        },
+    };
+
+    let log_path = log_path.unwrap_or_else(submit::default_log_path);
+    let session = match client::load_session(site.session_file.as_deref()) {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        },
+    };
+    let mut log = match SubmissionLog::load(&log_path) {
+        Ok(log) => log,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        },
+    };
+
+    let client = Client::new(&site.base_url, site.year, session);
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("The clock is before 1970")
+        .as_secs();
+
+    println!("Day {day} part {part}: submitting {answer}");
+    match submit::submit(&client, &mut log, &log_path, (day, part), &answer, now) {
+        Ok(Verdict::Correct) => {
+            println!("{}", Verdict::Correct);
+
+            // keep `verify` in step with what the site accepted, for the year it checks
+            if site.year != DEFAULT_YEAR {
+                return true;
+            }
+            let answers_path = answers::default_path();
+            let recorded = Answers::load(&answers_path)
+                .and_then(|mut answers| {
+                    answers.insert(day, part, &Answer::from(answer));
+                    answers.save(&answers_path)
+                });
+            if let Err(err) = recorded {
+                eprintln!("{err}");
+            }
+
+            true
+        },
+        Ok(verdict @ Verdict::AlreadySolved) => {
+            println!("{verdict}");
+            true
+        },
+        Ok(verdict) => {
+            println!("{verdict}");
+            false
+        },
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        },
+    }
+}
+
+/// The given seed, or a new one that's printed so the run can be repeated.
+fn seed_or_new(seed: Option<u64>) -> u64 {
+    seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("The clock is before 1970")
+            .as_nanos() as u64;
+        eprintln!("Seed: {seed}");
+        seed
+    })
+}
+
+fn generate(day: u8, size: NonZeroUsize, seed: Option<u64>) -> bool {
+    let seed = seed_or_new(seed);
+
+    let Some(day) = year().day(day) else {
+        eprintln!("day {day} isn't registered");
+        return false;
+    };
+
+    match day.generate(&mut Rng::new(seed), size.get()) {
+        Some(input) => {
+            print!("{input}");
+            true
+        },
+        None => {
+            eprintln!("day {} has no input generator", day.day);
+            false
+        },
+    }
+}
+
+fn cross_check(day: u8, cases: NonZeroUsize, size: NonZeroUsize, seed: Option<u64>) -> bool {
+    let seed = seed_or_new(seed);
+
+    let Some(day) = year().day(day) else {
+        eprintln!("day {day} isn't registered");
+        return false;
+    };
+
+    let seeds = (0..cases.get() as u64).map(|i| seed.wrapping_add(i));
+    match cross_check::cross_check(day, seeds, size.get()) {
+        Ok(compared) => {
+            println!("Day {}: {compared} answers agree", day.day);
+            true
+        },
+        Err(err) => {
+            eprintln!("day {}: {err}", day.day);
+            if let Some(seed) = err.seed() {
+                eprintln!("Regenerate the input with `generate {} --size {size} --seed {seed}`", day.day);
+            }
+            false
+        },
+    }
+}
+
+fn watch(day: u8, input: Option<PathBuf>, example: Option<PathBuf>, interval: u64) -> bool {
+    let Some(day) = year().day(day) else {
+        eprintln!("day {day} isn't registered");
+        return false;
+    };
+
+    let input = input.unwrap_or_else(|| input::default_path(day.day));
+    let source = scaffold::day_path(DEFAULT_YEAR, day.day);
+    let mut watcher = Watcher::new([input.clone(), source.clone()].into_iter().chain(example.clone()));
+
+    let mut last_input = None;
+    let mut last_example = None;
+    let mut changed = vec![input.clone()];
+
+    loop {
+        // the solver is compiled in, so there's nothing to re-run until it's rebuilt
+        if changed.contains(&source) {
+            eprintln!("{} changed: rebuild and restart to run the new code", source.display());
+        }
+
+        if changed.iter().any(|path| *path != source) {
+            println!("--- Day {} ---", day.day);
+
+            let answers = watch::solve(day, &input, false);
+            watch::diff("Part", last_input.as_ref(), &answers).iter().for_each(|line| println!("{line}"));
+            last_input = Some(answers);
+
+            if let Some(example) = &example {
+                let answers = watch::solve(day, example, true);
+                watch::diff("Example part", last_example.as_ref(), &answers).iter().for_each(|line| println!("{line}"));
+                last_example = Some(answers);
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(interval));
+        changed = watcher.poll().into_iter().map(Path::to_owned).collect();
+    }
+}
+
+struct AnimateArgs {
+    fps: f64,
+    every: NonZeroUsize,
+    images: Option<PathBuf>,
+    format: ImageFormat,
+    scale: NonZeroUsize,
+}
+
+fn animate(day: u8, part: Part, input: Option<InputSource>, args: AnimateArgs) -> bool {
+    let Some(day) = year().day(day) else {
+        eprintln!("day {day} isn't registered");
+        return false;
+    };
+
+    let puzzle_input = match input::load(day.day, &input.unwrap_or_default()) {
+        Ok(puzzle_input) => puzzle_input,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        },
+    };
+    let parsed = match day.parse(&puzzle_input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        },
+    };
+
+    let sink: Box<dyn FrameSink> = match &args.images {
+        Some(dir) => match Images::new(dir.clone(), args.format, args.scale.get()) {
+            Ok(images) => Box::new(images),
+            Err(err) => {
+                eprintln!("couldn't create {}: {err}", dir.display());
+                return false;
+            },
+        },
+        None => Box::new(Terminal::stdout(args.fps)),
+    };
+    let mut sink = EveryNth::new(sink, args.every.get());
+
+    let played = match day.animate(part, &parsed, &mut sink) {
+        Some(played) => played.and_then(|_| sink.finish()),
+        None => {
+            eprintln!("day {} part {part} has no simulation to animate", day.day);
+            return false;
+        },
+    };
+
+    match (played, args.images) {
+        (Ok(()), Some(dir)) => {
+            println!("Wrote frames to {}", dir.display());
+            true
+        },
+        (Ok(()), None) => true,
+        (Err(err), _) => {
+            eprintln!("couldn't write a frame: {err}");
+            false
+        },
+    }
+}
+
+fn new_day(year: u16, day: u8) -> bool {
+    match scaffold::new_day(Path::new("."), year, day) {
+        Ok(written) => {
+            written.iter().for_each(|path| println!("Wrote {}", path.display()));
+            true
+        },
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        },
+    }
+}
+
+fn enable_tracing(verbose: u8, days: Option<&DaySelection>) {
+    let level = match (verbose, days) {
+        (0, None) => return,
+        (0, Some(_)) => Level::Trace,
+        (1, _) => Level::Info,
+        (2, _) => Level::Debug,
+        _ => Level::Trace,
+    };
+
+    trace::enable(level, (FIRST_DAY..=LAST_DAY).filter(|&day| days.is_none_or(|days| days.contains(day))));
+}
 
 fn main() {
-    day_1::day_1();
-    day_2::day_2();
-    day_3::day_3();
-    day_4::day_4();
-    day_5::day_5();
-    day_6::day_6();
-    day_7::day_7();
-    day_8::day_8();
-    day_9::day_9();
-    day_10::day_10();
-    day_11::day_11();
-    day_12::day_12();
-    day_13::day_13();
-    day_14::day_14();
-    day_15::day_15();
-    day_16::day_16();
-    day_17::day_17();
-    day_18::day_18();
-    day_19::day_19();
-    day_20::day_20();
-    day_21::day_21();
-    day_22::day_22();
-    day_23::day_23();
-    day_24::day_24();
-    day_25::day_25();
+    let cli = Cli::parse();
+    enable_tracing(cli.verbose, cli.trace.as_ref());
+    if cli.checked {
+        checked::enable();
+    }
+
+    let ok = match cli.command.unwrap_or(Command::Run { days: DaySelection::all(), part: None, input: None, example: false, jobs: None, format: Format::Text, allocations: false }) {
+        Command::Run { days, part, example: true, .. } => run_examples(days, part),
+        Command::Run { days, part, input, jobs, format, allocations: count, .. } => {
+            if count {
+                allocations::enable();
+            }
+            run(days, part, input, jobs, format)
+        },
+        Command::Bench { days, part, input, iterations, allocations: count } => {
+            if count {
+                allocations::enable();
+            }
+            bench(days, part, input, iterations)
+        },
+        Command::Verify { days, part, answers, record } => verify(days, part, answers, record),
+        Command::Fetch { days, site } => fetch(days, site),
+        Command::Submit { day, part, answer, log, site } => submit(day, part, answer, log, site),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::CrossCheck { day, cases, size, seed } => cross_check(day, cases, size, seed),
+        Command::Animate { day, part, input, fps, every, images, format, scale } => {
+            animate(day, part, input, AnimateArgs { fps, every, images, format, scale })
+        },
+        Command::Watch { day, input, example, interval } => watch(day, input, example, interval),
+        Command::New { day, year } => new_day(year, day),
+    };
+
+    if !ok {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,110 @@
+use std::{borrow::Borrow, fmt::Display, hash::Hash};
+
+use fxhash::FxHashMap;
+
+/// A cache for a recursive function, which is handed back to the function so it can recurse through it.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: FxHashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+/// How well a [`Memo`] has done since it was made or last cleared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// How many values are cached.
+    pub size: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} hits, {} misses, {} cached", self.hits, self.misses, self.size)
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self { cache: Default::default(), hits: 0, misses: 0 }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached value for `key`, or else the value `f` calculates, which is cached for next time.
+    ///
+    /// The key is only copied into the cache on a miss, so e.g. a `Memo<Vec<T>, _>` can be looked up by slice.
+    pub fn get_or_insert_with<Q>(&mut self, key: &Q, f: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(self);
+        self.cache.insert(key.to_owned(), value.clone());
+
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats { hits: self.hits, misses: self.misses, size: self.cache.len() }
+    }
+
+    /// Forgets everything, e.g. before moving on to an input the cached values don't hold for.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Memo, Stats};
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+
+        memo.get_or_insert_with(&n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn caches_recursive_calls() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        // each of 2..=90 is worked out once, and all but 89 and 90 are found again by their grandparent
+        assert_eq!(memo.stats(), Stats { hits: 87, misses: 89, size: 89 });
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        assert_eq!(memo.stats(), Stats { hits: 88, misses: 89, size: 89 });
+
+        memo.clear();
+        assert_eq!(memo.stats(), Stats::default());
+        assert_eq!(fibonacci(10, &mut memo), 55);
+        assert_eq!(memo.stats().size, 9);
+    }
+
+    #[test]
+    fn looks_up_borrowed_keys() {
+        let mut memo: Memo<Vec<u8>, usize> = Memo::new();
+        let word = b"abcabc";
+
+        let calls = (0..3).map(|_| memo.get_or_insert_with(&word[..3], |_| 1) + memo.get_or_insert_with(&word[3..], |_| 2)).collect::<Vec<_>>();
+
+        assert_eq!(calls, [2, 2, 2]);
+        assert_eq!(memo.stats(), Stats { hits: 5, misses: 1, size: 1 });
+    }
+}
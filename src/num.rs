@@ -0,0 +1,247 @@
+use num_traits::PrimInt;
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        (a, b) = (b, a % b);
+    }
+
+    // the remainder takes the sign of the dividend, so this can come out negative for signed types
+    if a < T::zero() { T::zero() - a } else { a }
+}
+
+/// The least common multiple, which is never negative, or `None` if it doesn't fit in `T`.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> Option<T> {
+    if a == T::zero() || b == T::zero() {
+        return Some(T::zero());
+    }
+
+    let lcm = (a / gcd(a, b)).checked_mul(&b)?;
+    if lcm < T::zero() { T::zero().checked_sub(&lcm) } else { Some(lcm) }
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is `gcd(a, b)`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r, mut next_r) = (a, b);
+    let (mut x, mut next_x) = (1, 0);
+    let (mut y, mut next_y) = (0, 1);
+
+    while next_r != 0 {
+        let q = r / next_r;
+        (r, next_r) = (next_r, r - q * next_r);
+        (x, next_x) = (next_x, x - q * next_x);
+        (y, next_y) = (next_y, y - q * next_y);
+    }
+
+    if r < 0 { (-r, -x, -y) } else { (r, x, y) }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "The modulus must be positive");
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves `x ≡ r (mod m)` for every `(r, m)` at once (the Chinese remainder theorem), returning the smallest
+/// non-negative `x` and the modulus it repeats with.
+///
+/// The moduli don't have to be coprime, but `None` if the congruences contradict each other or the combined
+/// modulus doesn't fit in an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences.into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "The modulus must be positive");
+
+            let g = gcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != 0 {
+                return None;
+            }
+
+            // solve r1 + m1 * k ≡ r2 (mod m2) for k, which is unique mod m2 / g
+            let m = lcm(m1, m2)?;
+            let step = m2 / g;
+            let inverse = mod_inverse(m1 / g, step).expect("m1 / g and m2 / g are coprime");
+            let k = ((diff / g) as i128 * inverse as i128).rem_euclid(step as i128);
+            let r = (r1 as i128 + m1 as i128 * k).rem_euclid(m as i128);
+
+            Some((r as i64, m))
+        })
+}
+
+/// The whole-number solutions `(x, y)` of `x * a + y * b == target`, for two-dimensional `a` and `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearSolution {
+    /// The only solution isn't whole.
+    None,
+    One(i128, i128),
+    /// `a` and `b` are parallel, so there's either no solution or a whole line of them.
+    Parallel,
+}
+
+/// Solves `x * a + y * b == target` by Cramer's rule, staying in whole numbers until the last division so
+/// that there's no rounding to go wrong.
+pub fn solve_linear(a: [i64; 2], b: [i64; 2], target: [i64; 2]) -> LinearSolution {
+    let ([a0, a1], [b0, b1], [t0, t1]) = (a.map(i128::from), b.map(i128::from), target.map(i128::from));
+    let det = a0 * b1 - b0 * a1;
+    if det == 0 {
+        return LinearSolution::Parallel;
+    }
+
+    let (x_times_det, y_times_det) = (t0 * b1 - b0 * t1, a0 * t1 - t0 * a1);
+    if x_times_det % det != 0 || y_times_det % det != 0 {
+        return LinearSolution::None;
+    }
+
+    LinearSolution::One(x_times_det / det, y_times_det / det)
+}
+
+/// Working with the decimal digits of a number.
+pub trait Digits: Sized {
+    /// How many digits it's written with. 0 has one digit.
+    fn digits(self) -> u32;
+
+    /// Splits off the lowest `low` digits, e.g. `(12, 345)` from `12345` and 3.
+    fn split_digits(self, low: u32) -> (Self, Self);
+
+    /// `self` written followed by `other`, e.g. `12345` from `12` and `345`, unless it overflows.
+    fn checked_concat(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_digits {
+    ($($t:ty),*) => {
+        $(
+            impl Digits for $t {
+                fn digits(self) -> u32 {
+                    self.checked_ilog10().unwrap_or(0) + 1
+                }
+
+                fn split_digits(self, low: u32) -> (Self, Self) {
+                    match (10 as $t).checked_pow(low) {
+                        Some(magnitude) => (self / magnitude, self % magnitude),
+                        // every digit there is is one of the low ones
+                        None => (0, self),
+                    }
+                }
+
+                fn checked_concat(self, other: Self) -> Option<Self> {
+                    (10 as $t).checked_pow(other.digits())?
+                        .checked_mul(self)?
+                        .checked_add(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{crt, extended_gcd, gcd, lcm, mod_inverse, solve_linear, Digits, LinearSolution};
+
+    proptest! {
+        #[test]
+        fn gcd_is_the_greatest_common_divisor(a in -100_000i64..100_000, b in -100_000i64..100_000) {
+            let g = gcd(a, b);
+            prop_assert!(g >= 0);
+
+            if g == 0 {
+                prop_assert!(a == 0 && b == 0);
+            } else {
+                prop_assert_eq!((a % g, b % g), (0, 0));
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn lcm_times_gcd_is_the_product(a in -100_000i64..100_000, b in -100_000i64..100_000) {
+            let l = lcm(a, b).unwrap();
+            prop_assert!(l >= 0);
+            prop_assert_eq!(l * gcd(a, b), (a * b).abs());
+        }
+
+        #[test]
+        fn lcm_overflows_gracefully(a in any::<u64>(), b in any::<u64>()) {
+            let expected = (a as u128 / gcd(a, b).max(1) as u128) * b as u128;
+            prop_assert_eq!(lcm(a, b), u64::try_from(expected).ok());
+        }
+
+        #[test]
+        fn extended_gcd_finds_bezout_coefficients(a in -1_000_000i64..1_000_000, b in -1_000_000i64..1_000_000) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(g, gcd(a, b));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+
+        #[test]
+        fn mod_inverse_undoes_multiplication(a in -1_000_000i64..1_000_000, m in 1i64..10_000) {
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    prop_assert!((0..m).contains(&inverse));
+                    prop_assert_eq!((a * inverse).rem_euclid(m), 1 % m);
+                },
+                None => prop_assert_ne!(gcd(a, m), 1),
+            }
+        }
+
+        #[test]
+        fn crt_finds_the_number_from_its_remainders(x in 0i64..1_000_000_000, moduli in prop::collection::vec(1i64..200, 0..6)) {
+            let (r, m) = crt(moduli.iter().map(|&m| (x % m, m))).unwrap();
+
+            prop_assert_eq!(m, moduli.iter().fold(1, |l, &m| lcm(l, m).unwrap()));
+            prop_assert_eq!(r, x % m);
+        }
+
+        #[test]
+        fn solve_linear_finds_whole_solutions(
+            a in prop::array::uniform2(-1000i64..1000),
+            b in prop::array::uniform2(-1000i64..1000),
+            x in -1_000_000i64..1_000_000,
+            y in -1_000_000i64..1_000_000,
+            offset in prop::array::uniform2(0i64..3),
+        ) {
+            let target = [0, 1].map(|i| x * a[i] + y * b[i] + offset[i]);
+
+            match solve_linear(a, b, target) {
+                LinearSolution::One(x, y) => prop_assert_eq!([0, 1].map(|i| x * a[i] as i128 + y * b[i] as i128), target.map(i128::from)),
+                LinearSolution::None => prop_assert!(offset != [0, 0]),
+                LinearSolution::Parallel => prop_assert_eq!(a[0] * b[1], a[1] * b[0]),
+            }
+        }
+
+        #[test]
+        fn digits_are_counted_and_split(n in any::<u128>(), low in 0u32..45) {
+            let written = n.to_string();
+            prop_assert_eq!(n.digits() as usize, written.len());
+
+            let (high, low_digits) = n.split_digits(low);
+            let split = written.len().saturating_sub(low as usize);
+            prop_assert_eq!(high, written[..split].parse().unwrap_or(0));
+            prop_assert_eq!(low_digits, written[split..].parse().unwrap_or(0));
+
+            let n = n as u64;
+            prop_assert_eq!(n.digits() as usize, n.to_string().len());
+        }
+
+        #[test]
+        fn concat_writes_one_number_after_the_other(a in any::<u64>(), b in any::<u64>(), shift in 0u32..20) {
+            // shifting makes short numbers, which don't overflow, as likely as long ones
+            let (a, b) = (a >> (shift * 3), b >> (shift * 3));
+            prop_assert_eq!(a.checked_concat(b), format!("{a}{b}").parse().ok());
+            prop_assert_eq!((a as u128).checked_concat(b as u128), format!("{a}{b}").parse().ok());
+        }
+    }
+
+    #[test]
+    fn crt_spots_contradictions() {
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(0, 1 << 62), (0, 3)]), None);
+    }
+}
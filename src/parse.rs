@@ -0,0 +1,132 @@
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use crate::grid::GridError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidNumber(ParseIntError),
+    /// What the puzzle format has at this point instead, e.g. "two numbers" or "`|`"
+    Expected(&'static str),
+    Grid(GridError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::InvalidNumber(err) => write!(f, "invalid number ({err})"),
+            ParseErrorKind::Expected(what) => write!(f, "expected {what}"),
+            ParseErrorKind::Grid(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// 1-based, counting characters rather than bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Part of a puzzle input that doesn't match the format the day expects.
+///
+/// Parsers only need the offending slice of the input; the day and the line and column
+/// it's on are filled in by [`ParseError::in_day`] once the whole input is known.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub location: Option<Location>,
+    pub text: String,
+    pub kind: ParseErrorKind,
+    /// Where `text` was in memory, to find it again in the whole input. Never dereferenced.
+    address: usize,
+}
+
+impl ParseError {
+    /// `text` must be a slice of the input being parsed, so that it can be located later.
+    pub fn new(text: &str, kind: ParseErrorKind) -> Self {
+        Self {
+            day: None,
+            location: None,
+            text: text.to_owned(),
+            kind,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    pub fn expected(text: &str, what: &'static str) -> Self {
+        Self::new(text, ParseErrorKind::Expected(what))
+    }
+
+    /// Finds where the error is in `input`, which should be the whole input that was parsed.
+    pub fn in_day(self, day: u8, input: &str) -> Self {
+        let location = self.address.checked_sub(input.as_ptr() as usize)
+            .and_then(|offset| input.get(..offset))
+            .map(|before| Location {
+                line: before.matches('\n').count() + 1,
+                column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+            });
+
+        Self { day: Some(day), location: location.or(self.location), ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        if let Some(Location { line, column }) = self.location {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(f, "{}", self.kind)?;
+
+        // only the first line, so a bad map doesn't get printed in full
+        match self.text.lines().next() {
+            Some(line) if !line.is_empty() => write!(f, ", found `{line}`"),
+            _ => write!(f, ", found the end of the input"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text` as a number, pointing at it if it isn't one.
+pub fn number<T: FromStr<Err = ParseIntError>>(text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|err| ParseError::new(text, ParseErrorKind::InvalidNumber(err)))
+}
+
+/// Splits `text` at the first `delimiter`, which is described as `what` if it's missing.
+pub fn split_once<'a>(text: &'a str, delimiter: &str, what: &'static str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter).ok_or_else(|| ParseError::expected(text, what))
+}
+
+/// The empty slice just past the end of `text`, for errors about something missing there.
+pub fn end_of(text: &str) -> &str {
+    &text[text.len()..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number, split_once, Location, ParseErrorKind};
+
+    #[test]
+    fn locates_errors_in_the_input() {
+        let input = "1 2\n3 x4\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = number::<u32>(line.split_whitespace().nth(1).unwrap()).unwrap_err().in_day(1, input);
+
+        assert_eq!(err.location, Some(Location { line: 2, column: 3 }));
+        assert_eq!(err.text, "x4");
+        assert!(matches!(err.kind, ParseErrorKind::InvalidNumber(_)));
+        assert_eq!(err.to_string(), "day 1, line 2, column 3: invalid number (invalid digit found in string), found `x4`");
+    }
+
+    #[test]
+    fn errors_outside_the_input_have_no_location() {
+        let other = String::from("something else");
+        let err = split_once("a-b", "|", "`|`").unwrap_err().in_day(5, &other);
+
+        assert_eq!(err.location, None);
+        assert_eq!(err.to_string(), "day 5, expected `|`, found `a-b`");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{solution::Day, year2024};
+
+/// One December's puzzles.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+pub const YEARS: &[Year] = &[
+    Year { year: 2024, days: year2024::DAYS },
+];
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The solution for `day` of `year`, if there is one.
+pub fn day(year: u16, day: u8) -> Option<&'static Day> {
+    self::year(year)?.day(day)
+}
@@ -0,0 +1,188 @@
+use std::io::Write;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{runner::{DayResult, Outcome, PartResult}, solution::{Answer, Part}};
+
+/// Bump this whenever a field is added, removed or changes meaning.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    /// The part ran, but found nothing to answer with.
+    NoAnswer,
+    NoInput,
+    InvalidInput,
+    Panicked,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub schema_version: u32,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub elapsed_ms: Option<f64>,
+    /// The allocation counts are only there when they were asked for.
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    schema_version: u32,
+    results: &'a [Record],
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// One record for each part that was asked for, even if the day failed before reaching it.
+pub fn records(result: &DayResult, parts: &[Part]) -> Vec<Record> {
+    let record = |part, status, error: Option<String>| Record {
+        schema_version: SCHEMA_VERSION,
+        day: result.day,
+        part: part_number(part),
+        status,
+        answer: None,
+        elapsed_ms: None,
+        allocations: None,
+        allocated_bytes: None,
+        peak_bytes: None,
+        error,
+    };
+
+    match &result.outcome {
+        Outcome::Solved { parts, .. } => parts.iter()
+            .map(|PartResult { part, answer, elapsed, allocations }| {
+                let (status, answer, error) = match answer {
+                    Ok(Answer::None) => (Status::NoAnswer, None, None),
+                    Ok(answer) => (Status::Ok, Some(answer.to_string()), None),
+                    Err(message) => (Status::Panicked, None, Some(message.clone())),
+                };
+
+                Record {
+                    answer,
+                    elapsed_ms: Some(elapsed.as_secs_f64() * 1000.0),
+                    allocations: allocations.map(|a| a.count),
+                    allocated_bytes: allocations.map(|a| a.bytes),
+                    peak_bytes: allocations.map(|a| a.peak),
+                    ..record(*part, status, error)
+                }
+            })
+            .collect(),
+        Outcome::NoInput(err) => parts.iter()
+            .map(|&part| record(part, Status::NoInput, Some(err.to_string())))
+            .collect(),
+        Outcome::InvalidInput(err) => parts.iter()
+            .map(|&part| record(part, Status::InvalidInput, Some(err.to_string())))
+            .collect(),
+        Outcome::Panicked(message) => parts.iter()
+            .map(|&part| record(part, Status::Panicked, Some(message.clone())))
+            .collect(),
+    }
+}
+
+pub fn write_json(records: &[Record], out: impl Write) -> serde_json::Result<()> {
+    serde_json::to_writer_pretty(out, &Report { schema_version: SCHEMA_VERSION, results: records })
+}
+
+pub fn write_csv(records: &[Record], out: impl Write) -> csv::Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    records.iter().try_for_each(|record| writer.serialize(record))?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{client::stub::temp_dir, input::InputSource, parse::{self, ParseError}, runner, solution::{Answer, Day, Part, Solution}};
+
+    use super::{records, write_csv, write_json, Record, Status, SCHEMA_VERSION};
+
+    /// Has no answer for part 1, and panics in part 2.
+    struct Unlucky;
+
+    impl Solution for Unlucky {
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse::number(input)
+        }
+
+        fn part_1(_n: &Self::Input) -> Answer {
+            Answer::None
+        }
+
+        fn part_2(n: &Self::Input) -> Answer {
+            panic!("{n} is unlucky")
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Report {
+        schema_version: u32,
+        results: Vec<Record>,
+    }
+
+    fn unlucky_records(test: &str) -> Vec<Record> {
+        let path = temp_dir(test).join("day_13.txt");
+        std::fs::write(&path, "13").unwrap();
+
+        let result = runner::run_day(&Day::new::<Unlucky>(13), &InputSource::Path(path), &[Part::One, Part::Two]);
+        records(&result, &[Part::One, Part::Two])
+    }
+
+    #[test]
+    fn only_the_part_that_panicked_is_marked() {
+        let records = unlucky_records("report-statuses");
+
+        assert_eq!(records.iter().map(|r| r.status).collect::<Vec<_>>(), [Status::NoAnswer, Status::Panicked]);
+        assert_eq!((records[0].answer.as_deref(), records[0].error.as_deref()), (None, None));
+        assert!(records[1].error.as_ref().unwrap().contains("13 is unlucky"), "{:?}", records[1].error);
+        assert!(records.iter().all(|r| r.elapsed_ms.is_some()));
+    }
+
+    #[test]
+    fn round_trips_through_json_and_csv() {
+        // timings that read back exactly, as serde_json's float parsing can be out in the last bit
+        let records = unlucky_records("report-round-trip").into_iter()
+            .map(|record| Record { elapsed_ms: Some(1.5), ..record })
+            .collect::<Vec<_>>();
+
+        let mut json = Vec::new();
+        write_json(&records, &mut json).unwrap();
+        let report: Report = serde_json::from_slice(&json).unwrap();
+        assert_eq!(report.schema_version, SCHEMA_VERSION);
+        assert_eq!(report.results, records);
+
+        let mut csv = Vec::new();
+        write_csv(&records, &mut csv).unwrap();
+        let read = csv::Reader::from_reader(csv.as_slice())
+            .deserialize()
+            .collect::<Result<Vec<Record>, _>>()
+            .unwrap();
+        assert_eq!(read, records);
+    }
+}
@@ -0,0 +1,139 @@
+use std::{cell::RefCell, panic::{self, AssertUnwindSafe}, sync::Once, time::{Duration, Instant}};
+
+use rayon::{prelude::*, ThreadPoolBuilder};
+
+use crate::{allocations::{self, Allocations}, input::{self, InputError, InputSource}, parse::ParseError, solution::{Answer, Day, Part}};
+
+pub struct PartResult {
+    pub part: Part,
+    /// What the part panicked with, if it did, leaving the other parts to be run.
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+    /// Only counted if [`allocations::enable`] has been called.
+    pub allocations: Option<Allocations>,
+}
+
+pub enum Outcome {
+    Solved { parse: Duration, parse_allocations: Option<Allocations>, parts: Vec<PartResult> },
+    NoInput(InputError),
+    InvalidInput(ParseError),
+    /// Parsing panicked, so no part was run.
+    Panicked(String),
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub outcome: Outcome,
+}
+
+impl DayResult {
+    pub fn is_ok(&self) -> bool {
+        match &self.outcome {
+            Outcome::Solved { parts, .. } => parts.iter().all(|part| part.answer.is_ok()),
+            _ => false,
+        }
+    }
+
+    pub fn print(&self) {
+        println!("--- Day {} ---", self.day);
+
+        match &self.outcome {
+            Outcome::Solved { parse, parse_allocations, parts } => {
+                println!("Parsed in {}", usage(*parse, *parse_allocations));
+                parts.iter()
+                    .for_each(|PartResult { part, answer, elapsed, allocations }| match answer {
+                        Ok(answer) => println!("Part {part}: {answer} ({})", usage(*elapsed, *allocations)),
+                        Err(message) => eprintln!("day {} part {part}: {message}", self.day),
+                    });
+            },
+            Outcome::NoInput(err) => eprintln!("{err}"),
+            Outcome::InvalidInput(err) => eprintln!("{err}"),
+            Outcome::Panicked(message) => eprintln!("day {}: {message}", self.day),
+        }
+    }
+}
+
+/// How long something took, and what it allocated if that was counted.
+fn usage(elapsed: Duration, allocations: Option<Allocations>) -> String {
+    match allocations {
+        Some(allocations) => format!("{elapsed:.2?}, {allocations}"),
+        None => format!("{elapsed:.2?}"),
+    }
+}
+
+thread_local! {
+    /// Set while a day is running, so the panic hook knows to keep its message for the report.
+    static CAPTURED_PANIC: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            CAPTURED_PANIC.with_borrow_mut(|captured| match captured {
+                Some(message) => *message = Some(info.to_string()),
+                None => default_hook(info),
+            });
+        }));
+    });
+}
+
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_panic_hook();
+    // put back whatever was capturing before, as parts are caught within their day
+    let outer = CAPTURED_PANIC.replace(Some(None));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let message = CAPTURED_PANIC.replace(outer).flatten();
+
+    result.map_err(|_| message.unwrap_or_else(|| "panicked".to_owned()))
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub fn run_day(day: &Day, input: &InputSource, parts: &[Part]) -> DayResult {
+    let puzzle_input = match input::load(day.day, input) {
+        Ok(puzzle_input) => puzzle_input,
+        Err(err) => return DayResult { day: day.day, outcome: Outcome::NoInput(err) },
+    };
+
+    let outcome = catch_panic(|| {
+        let ((parsed, parse), parse_allocations) = allocations::measure(|| time(|| day.parse(&puzzle_input)));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => return Outcome::InvalidInput(err),
+        };
+
+        let parts = parts.iter()
+            .filter_map(|&part| {
+                let ((answer, elapsed), allocations) = allocations::measure(|| time(|| catch_panic(|| day.solve(part, &parsed))));
+                Some(PartResult { part, answer: answer.transpose()?, elapsed, allocations })
+            })
+            .collect();
+
+        Outcome::Solved { parse, parse_allocations, parts }
+    });
+
+    DayResult { day: day.day, outcome: outcome.unwrap_or_else(Outcome::Panicked) }
+}
+
+/// Runs each day on its own thread from a pool of `threads` (or one per CPU if zero).
+/// The results come back in the same order as `days`.
+pub fn run_parallel(days: &[(&Day, Vec<Part>)], input: &InputSource, threads: usize) -> Vec<DayResult> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("Couldn't start thread pool");
+
+    pool.install(|| {
+        days.par_iter()
+            .map(|(day, parts)| run_day(day, input, parts))
+            .collect()
+    })
+}
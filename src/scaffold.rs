@@ -0,0 +1,231 @@
+use std::{fmt::Display, fs::OpenOptions, io::{ErrorKind, Write}, path::{Path, PathBuf}};
+
+use regex::Regex;
+
+use crate::input;
+
+/// The module that declares a year's days and lists them in `DAYS`, relative to the repository root.
+pub fn year_path(year: u16) -> PathBuf {
+    ["src", &format!("year{year}.rs")].iter().collect()
+}
+
+pub fn day_path(year: u16, day: u8) -> PathBuf {
+    ["src", &format!("year{year}"), &format!("day_{day}.rs")].iter().collect()
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// Never overwritten, in case it already holds a solution.
+    Exists(PathBuf),
+    AlreadyRegistered(u8),
+    /// The year has no module yet, so there's nowhere to register the day.
+    NoYear(PathBuf),
+    /// The year module doesn't have the `pub mod day_N;` lines or the `DAYS` entries to add this day next to.
+    NoRegistry(&'static str),
+    Io { path: PathBuf, error: std::io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::AlreadyRegistered(day) => write!(f, "day {day} is already registered"),
+            ScaffoldError::NoYear(path) => write!(f, "there's no {} to register the day in", path.display()),
+            ScaffoldError::NoRegistry(what) => write!(f, "couldn't find the {what} to add the day to"),
+            ScaffoldError::Io { path, error } => write!(f, "couldn't write {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// The starting point for a new day: a solution to fill in, and a test for its example.
+pub fn template(day: u8) -> String {
+    format!(r#"use crate::{{parse::ParseError, solution::{{Answer, Example, Solution}}}};
+
+const EXAMPLE: &str = r"";
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = ();
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {{ input: EXAMPLE, part_1: None, part_2: None }},
+    ];
+
+    fn parse(_input: &str) -> Result<Self::Input, ParseError> {{
+        todo!()
+    }}
+
+    fn part_1(_input: &Self::Input) -> Answer {{
+        todo!()
+    }}
+
+    fn part_2(_input: &Self::Input) -> Answer {{
+        todo!()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use crate::solution::assert_examples;
+
+    use super::Day{day};
+
+    #[test]
+    fn examples() {{
+        assert_examples::<Day{day}>();
+    }}
+}}
+"#)
+}
+
+/// Adds `line` among the lines that `pattern` matches, keeping them ordered by the day they capture.
+fn insert_ordered(source: &str, pattern: &Regex, day: u8, line: String, what: &'static str) -> Result<String, ScaffoldError> {
+    let mut lines = source.split_inclusive('\n').collect::<Vec<_>>();
+    let days = lines.iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, pattern.captures(line)?[1].parse::<u8>().ok()?)))
+        .collect::<Vec<_>>();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(ScaffoldError::AlreadyRegistered(day));
+    }
+
+    // after the last earlier day, or before the first if there isn't one
+    let (neighbour, index) = days.iter()
+        .rfind(|&&(_, d)| d < day)
+        .map(|&(i, _)| (i, i + 1))
+        .or_else(|| days.first().map(|&(i, _)| (i, i)))
+        .ok_or(ScaffoldError::NoRegistry(what))?;
+
+    // match the indentation of the neighbouring entry
+    let neighbour = lines[neighbour];
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+    let line = format!("{indent}{line}\n");
+
+    lines.insert(index, &line);
+    Ok(lines.concat())
+}
+
+/// A year module's `source` with `day` declared as a module and added to `DAYS`.
+pub fn register(source: &str, day: u8) -> Result<String, ScaffoldError> {
+    let module = Regex::new(r"^pub mod day_(\d+);").unwrap();
+    let entry = Regex::new(r"^\s*Day::new::<day_(\d+)::Day\d+>\(\d+\),").unwrap();
+
+    let source = insert_ordered(source, &module, day, format!("pub mod day_{day};"), "`pub mod day_N;` lines")?;
+    insert_ordered(&source, &entry, day, format!("Day::new::<day_{day}::Day{day}>({day}),"), "`DAYS` entries")
+}
+
+fn create_new(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|error| match error.kind() {
+            ErrorKind::AlreadyExists => ScaffoldError::Exists(path.to_owned()),
+            _ => ScaffoldError::Io { path: path.to_owned(), error },
+        })
+}
+
+/// Creates the module and the empty input for `day` under `root` and registers it in its year,
+/// returning every file it wrote. An input that's already been fetched is left alone.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let day_path = root.join(day_path(year, day));
+    let year_path = root.join(year_path(year));
+    let input_path = root.join(input::default_path(day));
+
+    if day_path.exists() {
+        return Err(ScaffoldError::Exists(day_path));
+    }
+
+    // work out the registration first, so nothing is written if it can't be done
+    let source = std::fs::read_to_string(&year_path)
+        .map_err(|error| match error.kind() {
+            ErrorKind::NotFound => ScaffoldError::NoYear(year_path.clone()),
+            _ => ScaffoldError::Io { path: year_path.clone(), error },
+        })?;
+    let source = register(&source, day)?;
+
+    create_new(&day_path, &template(day))?;
+    std::fs::write(&year_path, source)
+        .map_err(|error| ScaffoldError::Io { path: year_path.clone(), error })?;
+
+    let mut written = vec![day_path, year_path];
+
+    if !input_path.exists() {
+        input_path.parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .map_err(|error| ScaffoldError::Io { path: input_path.clone(), error })?;
+        create_new(&input_path, "")?;
+        written.push(input_path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::stub::temp_dir;
+
+    use super::{new_day, register, ScaffoldError};
+
+    const YEAR: &str = "use crate::solution::Day;
+
+pub mod day_1;
+pub mod day_3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_3::Day3>(3),
+];
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        assert_eq!(register(YEAR, 2).unwrap(), "use crate::solution::Day;
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_2::Day2>(2),
+    Day::new::<day_3::Day3>(3),
+];
+");
+
+        let source = register(YEAR, 4).unwrap();
+        assert!(source.contains("pub mod day_3;\npub mod day_4;\n"), "{source}");
+        assert!(source.contains("(3),\n    Day::new::<day_4::Day4>(4),\n];"), "{source}");
+
+        assert!(matches!(register(YEAR, 3), Err(ScaffoldError::AlreadyRegistered(3))));
+        assert!(matches!(register("fn main() {}\n", 3), Err(ScaffoldError::NoRegistry(_))));
+    }
+
+    #[test]
+    fn never_overwrites_a_day() {
+        let root = temp_dir("scaffold");
+        std::fs::create_dir_all(root.join("src/year2024")).unwrap();
+        std::fs::write(root.join("src/year2024.rs"), YEAR).unwrap();
+
+        let written = new_day(&root, 2024, 2).unwrap();
+        assert_eq!(written, vec![root.join("src/year2024/day_2.rs"), root.join("src/year2024.rs"), root.join("inputs/day_2.txt")]);
+        assert!(std::fs::read_to_string(root.join("src/year2024/day_2.rs")).unwrap().contains("impl Solution for Day2 {"));
+        assert_eq!(std::fs::read_to_string(root.join("inputs/day_2.txt")).unwrap(), "");
+
+        std::fs::write(root.join("src/year2024/day_2.rs"), "// solved").unwrap();
+        assert!(matches!(new_day(&root, 2024, 2), Err(ScaffoldError::Exists(_))));
+        assert!(matches!(new_day(&root, 2023, 2), Err(ScaffoldError::NoYear(_))));
+        assert_eq!(std::fs::read_to_string(root.join("src/year2024/day_2.rs")).unwrap(), "// solved");
+
+        // an input fetched before the day was started is kept
+        std::fs::create_dir_all(root.join("inputs")).unwrap();
+        std::fs::write(root.join("inputs/day_4.txt"), "1 2 3").unwrap();
+        assert_eq!(new_day(&root, 2024, 4).unwrap().len(), 2);
+        assert_eq!(std::fs::read_to_string(root.join("inputs/day_4.txt")).unwrap(), "1 2 3");
+    }
+}
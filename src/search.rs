@@ -0,0 +1,274 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, VecDeque}, hash::Hash};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::checked;
+
+/// A node waiting in the priority queue. Ordered by `priority` only, and flipped
+/// so that `BinaryHeap` pops the cheapest first.
+struct Queued<N> {
+    priority: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Everything reachable from the start nodes: the cost of the cheapest path to each node,
+/// and every predecessor that node can be reached from at that cost.
+///
+/// The predecessors form a DAG of all the shortest paths back to the start.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: FxHashMap<N, u64>,
+    predecessors: FxHashMap<N, Vec<N>>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Self { distances: FxHashMap::default(), predecessors: FxHashMap::default() }
+    }
+
+    /// Records reaching `next` from `node` at `cost`, returning whether that's a new cheapest path.
+    fn relax(&mut self, node: N, next: N, cost: u64) -> bool {
+        match self.distances.get(&next) {
+            Some(&best) if cost > best => false,
+            Some(&best) if cost == best => {
+                self.predecessors.entry(next).or_default().push(node);
+                false
+            },
+            _ => {
+                self.distances.insert(next, cost);
+                self.predecessors.insert(next, vec![node]);
+                true
+            },
+        }
+    }
+
+    /// The cost of the cheapest path to `node`, or `None` if it can't be reached.
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.distances.get(&node).copied()
+    }
+
+    pub fn distances(&self) -> &FxHashMap<N, u64> {
+        &self.distances
+    }
+
+    /// The nodes directly before `node` on any of its shortest paths. Empty for the start nodes.
+    pub fn predecessors(&self, node: N) -> &[N] {
+        self.predecessors.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from a start node to `goal`, inclusive of both.
+    pub fn path_to(&self, goal: N) -> Option<Vec<N>> {
+        self.distance(goal)?;
+
+        let mut path = vec![goal];
+        while let Some(&prev) = self.predecessors(*path.last().unwrap()).first() {
+            path.push(prev);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every shortest path from a start node to `goal`. There can be exponentially many of these,
+    /// so prefer [`Search::nodes_on_paths_to`] if only the nodes matter.
+    pub fn all_paths_to(&self, goal: N) -> Vec<Vec<N>> {
+        if self.distance(goal).is_none() {
+            return Vec::new();
+        }
+
+        match self.predecessors(goal) {
+            [] => vec![vec![goal]],
+            prevs => prevs.iter()
+                .flat_map(|&prev| self.all_paths_to(prev))
+                .map(|mut path| {
+                    path.push(goal);
+                    path
+                })
+                .collect(),
+        }
+    }
+
+    /// Every node that lies on a shortest path to any of `goals`, including the goals themselves.
+    pub fn nodes_on_paths_to(&self, goals: impl IntoIterator<Item = N>) -> FxHashSet<N> {
+        let mut seen = FxHashSet::default();
+        let mut stack = goals.into_iter()
+            .filter(|&goal| self.distance(goal).is_some())
+            .collect::<Vec<_>>();
+
+        while let Some(node) = stack.pop() {
+            if seen.insert(node) {
+                stack.extend_from_slice(self.predecessors(node));
+            }
+        }
+
+        seen
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(N) -> I) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let cost = checked::add(search.distances[&node], 1, "a path's length");
+
+        for next in neighbours(node) {
+            if search.relax(node, next, cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm over weighted edges, exploring everything reachable from `starts`.
+pub fn dijkstra<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(N) -> I) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push(Queued { priority: 0, cost: 0, node: start });
+        }
+    }
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // already reached more cheaply through another path
+        if cost > search.distances[&node] { continue; }
+
+        for (next, weight) in neighbours(node) {
+            let next_cost = checked::add(cost, weight, "a path's cost");
+            if search.relax(node, next, next_cost) {
+                queue.push(Queued { priority: next_cost, cost: next_cost, node: next });
+            }
+        }
+    }
+
+    search
+}
+
+/// A* from `start` to the first node that `is_goal`, returning its cost and one path to it.
+///
+/// `heuristic` must never overestimate the remaining cost, or the path found might not be the cheapest.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> u64,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+
+    search.distances.insert(start, 0);
+    queue.push(Queued { priority: heuristic(start), cost: 0, node: start });
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if cost > search.distances[&node] { continue; }
+
+        if is_goal(node) {
+            return Some((cost, search.path_to(node).expect("The goal was reached")));
+        }
+
+        for (next, weight) in neighbours(node) {
+            let next_cost = checked::add(cost, weight, "a path's cost");
+            if search.relax(node, next, next_cost) {
+                let priority = checked::add(next_cost, heuristic(next), "a path's estimated cost");
+                queue.push(Queued { priority, cost: next_cost, node: next });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+
+    /// 0 -> 1 -> 3 and 0 -> 2 -> 3 both cost 2; 0 -> 3 directly costs 5.
+    fn diamond(node: u32) -> Vec<(u32, u64)> {
+        match node {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let search = bfs([0], |n: i32| [n - 1, n + 1].into_iter().filter(|n| (-3..=5).contains(n)));
+
+        assert_eq!(search.distance(5), Some(5));
+        assert_eq!(search.distance(-3), Some(3));
+        assert_eq!(search.distance(6), None);
+        assert_eq!(search.path_to(2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn dijkstra_keeps_every_shortest_path() {
+        let search = dijkstra([0], diamond);
+
+        assert_eq!(search.distance(3), Some(2));
+        assert_eq!(search.distance(4), Some(3));
+
+        let mut preds = search.predecessors(3).to_vec();
+        preds.sort();
+        assert_eq!(preds, vec![1, 2]);
+
+        let mut paths = search.all_paths_to(4);
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+
+        let mut nodes = search.nodes_on_paths_to([3]).into_iter().collect::<Vec<_>>();
+        nodes.sort();
+        assert_eq!(nodes, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn astar_finds_the_cheapest_goal() {
+        assert_eq!(astar(0, diamond, |_| 0, |n| n == 4), Some((3, vec![0, 1, 3, 4])));
+        assert_eq!(astar(0, diamond, |_| 0, |n| n == 5), None);
+    }
+}
@@ -0,0 +1,278 @@
+use std::{any::Any, fmt::Display, io};
+
+use clap::ValueEnum;
+use itertools::Itertools;
+
+use crate::{frames::FrameSink, generate::Rng, parse::ParseError, trace};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    /// Displayed comma-separated, e.g. the output of day 17's program
+    List(Vec<Answer>),
+    /// The input has no answer, e.g. day 18's bytes never cut off the exit
+    None,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{i}"),
+            Answer::String(s) => write!(f, "{s}"),
+            Answer::List(items) => write!(f, "{}", items.iter().join(",")),
+            Answer::None => write!(f, "no answer"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_owned())
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(value: Vec<T>) -> Self {
+        Answer::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::None, Into::into)
+    }
+}
+
+/// An example from the puzzle text, with the answers it gives.
+/// Parts without an expected answer aren't run on this example.
+pub struct Example {
+    pub input: &'static str,
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_1,
+            Part::Two => self.part_2,
+        }
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    /// Day 25 only has one puzzle.
+    const HAS_PART_2: bool = true;
+
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Some examples use a smaller map or a lower threshold than the real puzzle.
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    /// A random input in the puzzle's format for stress testing, or `None` if the day has no generator.
+    ///
+    /// `size` is how many lines or items to make, or the side of the map, depending on the puzzle.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    fn part_1(input: &Self::Input) -> Answer;
+    fn part_2(input: &Self::Input) -> Answer;
+
+    /// A slow but straightforward way to solve `part`, for cross-checking the real solution on generated inputs.
+    /// `None` if there isn't one, or if it can't handle this input.
+    fn reference(_part: Part, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// Pushes each step of the simulation behind `part` into `sink`, or `None` if the day doesn't simulate anything.
+    fn animate(_part: Part, _input: &Self::Input, _sink: &mut dyn FrameSink) -> Option<io::Result<()>> {
+        None
+    }
+}
+
+pub type ParsedInput = Box<dyn Any>;
+
+/// A type-erased [`Solution`], so that every day can live in the same registry.
+pub struct Day {
+    pub day: u8,
+    pub examples: &'static [Example],
+    parse: fn(&str) -> Result<ParsedInput, ParseError>,
+    parse_example: fn(&str) -> Result<ParsedInput, ParseError>,
+    generate: fn(&mut Rng, usize) -> Option<String>,
+    part_1: fn(&ParsedInput) -> Answer,
+    part_2: Option<fn(&ParsedInput) -> Answer>,
+    reference: fn(Part, &ParsedInput) -> Option<Answer>,
+    animate: fn(Part, &ParsedInput, &mut dyn FrameSink) -> Option<io::Result<()>>,
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<ParsedInput, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn parse_example_erased<S: Solution>(input: &str) -> Result<ParsedInput, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse_example(input)?))
+}
+
+fn downcast<S: Solution>(input: &ParsedInput) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input.downcast_ref().expect("Input was parsed by a different day")
+}
+
+fn part_1_erased<S: Solution>(input: &ParsedInput) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part_1(downcast::<S>(input))
+}
+
+fn part_2_erased<S: Solution>(input: &ParsedInput) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part_2(downcast::<S>(input))
+}
+
+fn reference_erased<S: Solution>(part: Part, input: &ParsedInput) -> Option<Answer>
+where
+    S::Input: 'static,
+{
+    S::reference(part, downcast::<S>(input))
+}
+
+fn animate_erased<S: Solution>(part: Part, input: &ParsedInput, sink: &mut dyn FrameSink) -> Option<io::Result<()>>
+where
+    S::Input: 'static,
+{
+    S::animate(part, downcast::<S>(input), sink)
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            day,
+            examples: S::EXAMPLES,
+            parse: parse_erased::<S>,
+            parse_example: parse_example_erased::<S>,
+            generate: S::generate,
+            part_1: part_1_erased::<S>,
+            part_2: if S::HAS_PART_2 { Some(part_2_erased::<S>) } else { None },
+            reference: reference_erased::<S>,
+            animate: animate_erased::<S>,
+        }
+    }
+
+    pub fn has_part(&self, part: Part) -> bool {
+        match part {
+            Part::One => true,
+            Part::Two => self.part_2.is_some(),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<ParsedInput, ParseError> {
+        trace::in_day(self.day, || (self.parse)(input)).map_err(|err| err.in_day(self.day, input))
+    }
+
+    pub fn parse_example(&self, input: &str) -> Result<ParsedInput, ParseError> {
+        trace::in_day(self.day, || (self.parse_example)(input)).map_err(|err| err.in_day(self.day, input))
+    }
+
+    /// See [`Solution::generate`].
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        (self.generate)(rng, size)
+    }
+
+    pub fn solve(&self, part: Part, input: &ParsedInput) -> Option<Answer> {
+        trace::in_day(self.day, || match part {
+            Part::One => Some((self.part_1)(input)),
+            Part::Two => self.part_2.map(|solve| solve(input)),
+        })
+    }
+
+    /// See [`Solution::reference`].
+    pub fn reference(&self, part: Part, input: &ParsedInput) -> Option<Answer> {
+        trace::in_day(self.day, || (self.reference)(part, input))
+    }
+
+    /// See [`Solution::animate`].
+    pub fn animate(&self, part: Part, input: &ParsedInput, sink: &mut dyn FrameSink) -> Option<io::Result<()>> {
+        trace::in_day(self.day, || (self.animate)(part, input, sink))
+    }
+}
+
+#[cfg(test)]
+pub fn assert_examples<S: Solution>() {
+    assert!(!S::EXAMPLES.is_empty(), "No examples to check");
+
+    S::EXAMPLES.iter()
+        .for_each(|example| {
+            let input = S::parse_example(example.input)
+                .unwrap_or_else(|err| panic!("{}", err.in_day(0, example.input)));
+
+            if let Some(expected) = example.part_1 {
+                assert_eq!(S::part_1(&input).to_string(), expected, "part 1");
+            }
+            if let Some(expected) = example.part_2 {
+                assert_eq!(S::part_2(&input).to_string(), expected, "part 2");
+            }
+        });
+}
@@ -0,0 +1,338 @@
+use std::{fmt::Display, io::ErrorKind, path::{Path, PathBuf}, time::Duration};
+
+use regex::Regex;
+
+use crate::{client::{Client, ClientError}, input::INPUT_DIR, solution::Part};
+
+pub fn default_log_path() -> PathBuf {
+    [INPUT_DIR, "submissions.txt"].iter().collect()
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way
+    Wrong,
+    AlreadySolved,
+    RateLimited(Duration),
+    /// The text of a page we don't know how to read
+    Unrecognised(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the `<article>` of the page returned after submitting.
+    pub fn from_page(page: &str) -> Self {
+        let article = Regex::new(r"(?s)<article>(.*?)</article>").unwrap()
+            .captures(page)
+            .map_or(page, |captures| captures.get(1).unwrap().as_str());
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(article, "");
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited(wait_time(&text).unwrap_or(Duration::from_secs(60)))
+        } else {
+            Verdict::Unrecognised(text)
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::Unrecognised(_) => "unrecognised",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer!"),
+            Verdict::TooHigh => write!(f, "wrong, the answer is too high"),
+            Verdict::TooLow => write!(f, "wrong, the answer is too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::AlreadySolved => write!(f, "this part is already solved"),
+            Verdict::RateLimited(wait) => write!(f, "submitted too recently, wait {}s", wait.as_secs()),
+            Verdict::Unrecognised(text) => write!(f, "couldn't understand the response: {text}"),
+        }
+    }
+}
+
+/// How long the page says to wait before submitting again, e.g. "You have 1m 5s left to wait"
+/// or "please wait 5 minutes before trying again".
+fn wait_time(text: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have ((?:\d+[hms] ?)+) left to wait").unwrap();
+    if let Some(captures) = left.captures(text) {
+        let secs = captures[1].split_whitespace()
+            .map(|part| {
+                let (n, unit) = part.split_at(part.len() - 1);
+                let n = n.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(n * 3600),
+                    "m" => Some(n * 60),
+                    _ => Some(n),
+                }
+            })
+            .sum::<Option<u64>>()?;
+
+        return Some(Duration::from_secs(secs));
+    }
+
+    let before = Regex::new(r"(?i)wait (one|\d+) minutes? before trying again").unwrap();
+    let minutes = before.captures(text)?;
+    let minutes = match &minutes[1] {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// One past submission. `retry_after` is when the site will next accept an answer for the same part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub time: u64,
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub retry_after: Option<u64>,
+    pub answer: String,
+}
+
+/// Every answer submitted so far, one `<unix time> <day>.<part> <verdict> <retry after|-> <answer>` per line.
+#[derive(Debug, Default)]
+pub struct SubmissionLog(Vec<Submission>);
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// Not sent, because the log says it can't be right or the site won't accept it yet
+    Refused(String),
+    Client(ClientError),
+    Log { path: PathBuf, error: String },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(reason) => write!(f, "not submitting: {reason}"),
+            SubmitError::Client(err) => write!(f, "{err}"),
+            SubmitError::Log { path, error } => write!(f, "submission log {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(value: ClientError) -> Self {
+        SubmitError::Client(value)
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(5, ' ');
+    let time: u64 = fields.next()?.parse().ok()?;
+    let (day, part) = fields.next()?.split_once('.')?;
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+    let verdict = fields.next()?;
+    let retry_after = match fields.next()? {
+        "-" => None,
+        t => Some(t.parse().ok()?),
+    };
+    let answer = fields.next()?.to_owned();
+
+    let verdict = match verdict {
+        "correct" => Verdict::Correct,
+        "too-high" => Verdict::TooHigh,
+        "too-low" => Verdict::TooLow,
+        "wrong" => Verdict::Wrong,
+        "already-solved" => Verdict::AlreadySolved,
+        "rate-limited" => Verdict::RateLimited(Duration::from_secs(retry_after.unwrap_or(time).saturating_sub(time))),
+        _ => Verdict::Unrecognised(String::new()),
+    };
+
+    Some(Submission { time, day: day.parse().ok()?, part, verdict, retry_after, answer })
+}
+
+impl SubmissionLog {
+    /// A missing file is treated as an empty log.
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        let error = |error: String| SubmitError::Log { path: path.to_owned(), error };
+
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(error(err.to_string())),
+        };
+
+        contents.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| parse_entry(line).ok_or_else(|| error(format!("line {} is malformed: `{line}`", i + 1))))
+            .try_collect()
+            .map(Self)
+    }
+
+    /// Appends `submission` to the file at `path`, as well as to the log in memory.
+    pub fn append(&mut self, path: &Path, submission: Submission) -> Result<(), SubmitError> {
+        use std::io::Write;
+
+        let Submission { time, day, part, verdict, retry_after, answer } = &submission;
+        let retry_after = retry_after.map_or("-".to_owned(), |t| t.to_string());
+        let line = format!("{time} {day}.{part} {} {retry_after} {answer}\n", verdict.name());
+
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::OpenOptions::new().create(true).append(true).open(path))
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| SubmitError::Log { path: path.to_owned(), error: err.to_string() })?;
+
+        self.0.push(submission);
+        Ok(())
+    }
+
+    /// Why `answer` shouldn't be sent at time `now`, judging by earlier submissions for the same part.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let previous = self.0.iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect::<Vec<_>>();
+
+        if let Some(correct) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Err(format!("day {day} part {part} was already solved with {}", correct.answer));
+        }
+
+        if let Some(wrong) = previous.iter().find(|s| s.answer == answer && s.verdict.is_wrong()) {
+            return Err(format!("{answer} was already submitted, and was {}", wrong.verdict));
+        }
+
+        // the site's hints rule out everything past the closest wrong guess on each side
+        if let Ok(answer) = answer.parse::<i128>() {
+            let bound = |verdict| previous.iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok());
+
+            if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| answer >= high) {
+                return Err(format!("{high} was already too high"));
+            }
+            if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| answer <= low) {
+                return Err(format!("{low} was already too low"));
+            }
+        }
+
+        if let Some(retry_after) = previous.iter().filter_map(|s| s.retry_after).max().filter(|&t| t > now) {
+            return Err(format!("the site won't accept another answer for {}s", retry_after - now));
+        }
+
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the log rules it out, then records the verdict in the log at `log_path`.
+pub fn submit(
+    client: &Client,
+    log: &mut SubmissionLog,
+    log_path: &Path,
+    (day, part): (u8, Part),
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, SubmitError> {
+    log.check(day, part, answer, now).map_err(SubmitError::Refused)?;
+
+    let page = client.post_answer(day, part, answer)?;
+    let verdict = Verdict::from_page(&page);
+
+    let wait = match &verdict {
+        Verdict::RateLimited(wait) => Some(*wait),
+        v if v.is_wrong() => wait_time(&page),
+        _ => None,
+    };
+    let retry_after = wait.map(|wait| now + wait.as_secs());
+
+    log.append(log_path, Submission { time: now, day, part, verdict: verdict.clone(), retry_after, answer: answer.to_owned() })?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{client::{stub::{temp_dir, Stub}, Client}, solution::Part};
+
+    use super::{submit, SubmissionLog, SubmitError, Verdict};
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+        make sure you're using the full input data. Please wait one minute before trying again. \
+        [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article></main>";
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer \
+        before trying again.  You have 1m 5s left to wait. [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> \
+        closer to finding the Chief Historian.</p></article>";
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(Verdict::from_page(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(Verdict::from_page(RATE_LIMITED), Verdict::RateLimited(Duration::from_secs(65)));
+        assert_eq!(Verdict::from_page(CORRECT), Verdict::Correct);
+        assert_eq!(
+            Verdict::from_page("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Verdict::AlreadySolved,
+        );
+        assert!(matches!(Verdict::from_page("<p>Something else</p>"), Verdict::Unrecognised(_)));
+    }
+
+    #[test]
+    fn refuses_answers_the_log_rules_out() {
+        let stub = Stub::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&stub.base_url, 2024, "abc123".to_owned());
+        let path = temp_dir("submit-log").join("submissions.txt");
+        let mut log = SubmissionLog::load(&path).unwrap();
+
+        assert_eq!(submit(&client, &mut log, &path, (1, Part::Two), "500", 1000).unwrap(), Verdict::TooHigh);
+
+        let refused = |log: &SubmissionLog, answer, now| {
+            matches!(submit(&client, &mut SubmissionLog(log.0.clone()), &path, (1, Part::Two), answer, now), Err(SubmitError::Refused(_)))
+        };
+        assert!(refused(&log, "500", 2000), "same wrong answer");
+        assert!(refused(&log, "501", 2000), "higher than a too-high answer");
+        assert!(refused(&log, "400", 1030), "still waiting out the penalty");
+
+        // reloaded from disk, so the log survives between runs
+        let mut log = SubmissionLog::load(&path).unwrap();
+        assert_eq!(submit(&client, &mut log, &path, (1, Part::Two), "400", 1060).unwrap(), Verdict::Correct);
+        assert!(refused(&log, "401", 5000), "already solved");
+
+        let requests = stub.requests();
+        assert!(requests[0].starts_with("POST /2024/day/1/answer "), "{}", requests[0]);
+        assert!(requests[0].ends_with("level=2&answer=500"), "{}", requests[0]);
+        assert_eq!(SubmissionLog::load(&path).unwrap().0.len(), 2);
+    }
+}
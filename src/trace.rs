@@ -0,0 +1,104 @@
+use std::{cell::Cell, fmt::{Arguments, Display}, sync::atomic::{AtomicU32, AtomicU8, Ordering}};
+
+use clap::ValueEnum;
+
+/// How much a day says about what it's doing, on stderr. Runs print nothing but answers by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Level {
+    /// A line or two per part, e.g. a summary of the input
+    Info = 1,
+    /// The working behind the answer
+    Debug = 2,
+    /// Every step, e.g. each cycle of a simulation
+    Trace = 3,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Info => f.pad("INFO"),
+            Level::Debug => f.pad("DEBUG"),
+            Level::Trace => f.pad("TRACE"),
+        }
+    }
+}
+
+/// The most detailed level being printed, or zero for none.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Bit `n` is set if day `n` is being traced.
+static DAYS: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    /// The day running on this thread, so that days run in parallel are traced separately.
+    static CURRENT_DAY: Cell<Option<u8>> = const { Cell::new(None) };
+}
+
+/// Prints messages up to `level` from each of `days`.
+pub fn enable(level: Level, days: impl IntoIterator<Item = u8>) {
+    let days = days.into_iter().fold(0, |mask, day| mask | 1 << day);
+    DAYS.store(days, Ordering::Relaxed);
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether a message at `level` from the day running on this thread would be printed.
+pub fn enabled(level: Level) -> bool {
+    if LEVEL.load(Ordering::Relaxed) < level as u8 {
+        return false;
+    }
+
+    CURRENT_DAY.get().is_some_and(|day| DAYS.load(Ordering::Relaxed) & 1 << day != 0)
+}
+
+/// Runs `f` as part of `day`, so that anything it traces is attributed to it.
+pub fn in_day<T>(day: u8, f: impl FnOnce() -> T) -> T {
+    let outer = CURRENT_DAY.replace(Some(day));
+    let result = f();
+    CURRENT_DAY.set(outer);
+
+    result
+}
+
+/// Only called through the macros, once they've checked [`enabled`].
+#[doc(hidden)]
+pub fn emit(level: Level, message: Arguments) {
+    let day = CURRENT_DAY.get().expect("Only days are traced");
+    eprintln!("{level:>5} day {day}: {message}");
+}
+
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit($level, format_args!($($arg)*));
+        }
+    };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::trace::log_at!($crate::trace::Level::Info, $($arg)*) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::trace::log_at!($crate::trace::Level::Debug, $($arg)*) };
+}
+
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::trace::log_at!($crate::trace::Level::Trace, $($arg)*) };
+}
+
+pub(crate) use {debug, info, log_at, trace};
+
+#[cfg(test)]
+mod tests {
+    use super::{enable, enabled, in_day, Level};
+
+    #[test]
+    fn only_traces_chosen_days() {
+        enable(Level::Debug, [6, 17]);
+
+        assert!(!enabled(Level::Info), "nothing outside a day");
+        assert!(in_day(17, || enabled(Level::Info) && enabled(Level::Debug) && !enabled(Level::Trace)));
+        assert!(!in_day(9, || enabled(Level::Info)));
+        assert!(in_day(9, || in_day(6, || enabled(Level::Debug)) && !enabled(Level::Debug)));
+    }
+}
@@ -0,0 +1,126 @@
+use std::{path::{Path, PathBuf}, time::SystemTime};
+
+use crate::{runner::catch_panic, solution::{Answer, Day, Part}};
+
+/// Enough about a file to notice it changing, without needing the platform's file notifications.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// `None` if the file isn't there, so that creating or deleting it counts as a change too.
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some(Stamp { modified: metadata.modified().ok(), len: metadata.len() })
+}
+
+/// Polls a set of files for changes.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<Stamp>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths.into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// The files that have changed since the last poll.
+    pub fn poll(&mut self) -> Vec<&Path> {
+        self.files.iter_mut()
+            .filter_map(|(path, last)| {
+                let now = stamp(path);
+                (now != *last).then(|| {
+                    *last = now;
+                    path.as_path()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Every part's answer from one run, or why there weren't any.
+pub type Answers = Result<Vec<(Part, Answer)>, String>;
+
+/// Solves both parts of `day` for the input at `path`, parsing it as an example if `example` is set.
+pub fn solve(day: &Day, path: &Path, example: bool) -> Answers {
+    let input = std::fs::read_to_string(path)
+        .map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+
+    catch_panic(|| {
+        let parsed = if example { day.parse_example(&input) } else { day.parse(&input) }
+            .map_err(|err| err.to_string())?;
+
+        Ok(Part::ALL.into_iter()
+            .filter_map(|part| Some((part, day.solve(part, &parsed)?)))
+            .collect())
+    })?
+}
+
+/// One line per part, e.g. `{label} 1: 42 (was 41)`, comparing with the previous run if there was one.
+pub fn diff(label: &str, previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    let answers = match current {
+        Ok(answers) => answers,
+        Err(err) => return vec![format!("{label}: {err}")],
+    };
+
+    answers.iter()
+        .map(|(part, answer)| {
+            let before = previous
+                .and_then(|previous| previous.as_ref().ok())
+                .and_then(|previous| previous.iter().find(|(p, _)| p == part))
+                .map(|(_, before)| before);
+
+            match before {
+                Some(before) if before == answer => format!("{label} {part}: {answer} (unchanged)"),
+                Some(before) => format!("{label} {part}: {answer} (was {before})"),
+                None => format!("{label} {part}: {answer}"),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::stub::temp_dir, solution::{Answer, Part}};
+
+    use super::{diff, Answers, Watcher};
+
+    #[test]
+    fn notices_changes() {
+        let dir = temp_dir("watch");
+        let (input, example) = (dir.join("input.txt"), dir.join("example.txt"));
+        std::fs::write(&input, "1").unwrap();
+
+        let mut watcher = Watcher::new([input.clone(), example.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        std::fs::write(&input, "12").unwrap();
+        assert_eq!(watcher.poll(), [input.as_path()]);
+        assert!(watcher.poll().is_empty());
+
+        // appearing and disappearing are changes too
+        std::fs::write(&example, "3").unwrap();
+        assert_eq!(watcher.poll(), [example.as_path()]);
+        std::fs::remove_file(&example).unwrap();
+        assert_eq!(watcher.poll(), [example.as_path()]);
+    }
+
+    #[test]
+    fn compares_with_the_last_answers() {
+        let before: Answers = Ok(vec![(Part::One, Answer::from(41)), (Part::Two, Answer::from("abc"))]);
+        let after: Answers = Ok(vec![(Part::One, Answer::from(42)), (Part::Two, Answer::from("abc"))]);
+
+        assert_eq!(diff("Part", None, &before), ["Part 1: 41", "Part 2: abc"]);
+        assert_eq!(diff("Part", Some(&before), &after), ["Part 1: 42 (was 41)", "Part 2: abc (unchanged)"]);
+        assert_eq!(diff("Part", Some(&Err("oops".to_owned())), &after), ["Part 1: 42", "Part 2: abc"]);
+        assert_eq!(diff("Example part", Some(&before), &Err("oops".to_owned())), ["Example part: oops"]);
+    }
+}
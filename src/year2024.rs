@@ -0,0 +1,104 @@
+use crate::solution::Day;
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_2::Day2>(2),
+    Day::new::<day_3::Day3>(3),
+    Day::new::<day_4::Day4>(4),
+    Day::new::<day_5::Day5>(5),
+    Day::new::<day_6::Day6>(6),
+    Day::new::<day_7::Day7>(7),
+    Day::new::<day_8::Day8>(8),
+    Day::new::<day_9::Day9>(9),
+    Day::new::<day_10::Day10>(10),
+    Day::new::<day_11::Day11>(11),
+    Day::new::<day_12::Day12>(12),
+    Day::new::<day_13::Day13>(13),
+    Day::new::<day_14::Day14>(14),
+    Day::new::<day_15::Day15>(15),
+    Day::new::<day_16::Day16>(16),
+    Day::new::<day_17::Day17>(17),
+    Day::new::<day_18::Day18>(18),
+    Day::new::<day_19::Day19>(19),
+    Day::new::<day_20::Day20>(20),
+    Day::new::<day_21::Day21>(21),
+    Day::new::<day_22::Day22>(22),
+    Day::new::<day_23::Day23>(23),
+    Day::new::<day_24::Day24>(24),
+    Day::new::<day_25::Day25>(25),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::{cross_check::cross_check, generate::Rng, runner::catch_panic, solution::Part};
+
+    use super::DAYS;
+
+    #[test]
+    fn generated_inputs_parse_and_solve() {
+        // lots of small inputs, where the edge cases are, and a few bigger ones
+        let cases = (1..=20).flat_map(|seed| [(seed, 1), (seed, 2), (seed, 5)])
+            .chain((1..=3).map(|seed| (seed, 30)))
+            .collect::<Vec<_>>();
+
+        for day in DAYS {
+            for &(seed, size) in &cases {
+                let input = day.generate(&mut Rng::new(seed), size)
+                    .unwrap_or_else(|| panic!("day {} has no generator", day.day));
+
+                let parsed = day.parse(&input)
+                    .unwrap_or_else(|err| panic!("seed {seed}, size {size}: {err}\n{input}"));
+
+                for part in Part::ALL {
+                    if let Err(message) = catch_panic(|| day.solve(part, &parsed)) {
+                        panic!("day {} part {part}, seed {seed}, size {size}: {message}\n{input}", day.day);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solutions_match_references() {
+        for day in [11, 22, 24] {
+            let day = DAYS.iter().find(|d| d.day == day).unwrap();
+            if let Err(err) = cross_check(day, 0..5, 4) {
+                panic!("day {}: {err}", day.day);
+            }
+        }
+    }
+
+    #[test]
+    fn generators_are_reproducible() {
+        for day in DAYS {
+            let generate = |seed| day.generate(&mut Rng::new(seed), 10);
+            assert_eq!(generate(7), generate(7), "day {}", day.day);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+
+use crate::{checked::Sum, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("11"), part_2: Some("31") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (mut list1, mut list2): (Vec<_>, Vec<_>) = input
+            .lines()
+            .map(|line| {
+                let (a, b) = line.split_whitespace()
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::expected(line, "two numbers"))?;
+                Ok((parse::number::<u32>(a)?, parse::number::<u32>(b)?))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
+            .unzip();
+
+        list1.sort();
+        list2.sort();
+
+        Ok((list1, list2))
+    }
+
+    /// `size` pairs of five-digit location IDs, where some IDs are in both lists.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let left = (0..size).map(|_| rng.range(10000..=99999)).collect::<Vec<_>>();
+        let lines = left.iter()
+            .map(|&id| {
+                let other = if rng.chance(0.3) { *rng.choose(&left) } else { rng.range(10000..=99999) };
+                format!("{id}   {other}\n")
+            })
+            .collect();
+
+        Some(lines)
+    }
+
+    fn part_1((list1, list2): &Self::Input) -> Answer {
+        let sum_diffs = Iterator::zip(list1.iter(), list2.iter())
+            .map(|(a, b)| a.abs_diff(*b) as u64)
+            .checked_sum("the total distance");
+
+        sum_diffs.into()
+    }
+
+    fn part_2((list1, list2): &Self::Input) -> Answer {
+        let mut counts: BTreeMap<u32, u64> = BTreeMap::new();
+        list2.iter().for_each(|&i| {
+            let entry = counts.entry(i).or_insert(0);
+            *entry += 1;
+        });
+
+        // each product fits, but enough large ones can add up to more than a u64
+        let similarity = list1
+            .iter()
+            .map(|&i| i as u64 * counts.get(&i).copied().unwrap_or(0))
+            .checked_sum("the similarity score");
+
+        similarity.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day1;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day1>();
+    }
+}
@@ -0,0 +1,92 @@
+use itertools::Itertools;
+use ndarray::Ix2;
+
+use crate::{checked::Sum, generate::Rng, grid::{Grid, Pos}, parse::ParseError, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+type Map = Grid<u32>;
+
+fn check_paths(pos: Pos, map: &Map) -> Vec<Pos> {
+    let value = map[pos];
+    if value == 9 { vec![pos] }
+    else {
+        map.neighbours_4(pos)
+            .filter(|&adj_pos| map[adj_pos].checked_sub(value).is_some_and(|v| v == 1))
+            .flat_map(|adj_pos| check_paths(adj_pos, map))
+            .collect::<Vec<_>>()
+    }
+}
+
+fn trailheads(map: &Map) -> impl Iterator<Item = Pos> + '_ {
+    map.indexed_iter()
+        .filter(|(_, &value)| value == 0)
+        .map(|(pos, _)| pos)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("36"), part_2: Some("81") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    /// A `size` × `size` map of random heights with `size` hiking trails walked into it.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut map = Grid::from_fn(size, size, |_| rng.range(0..=9) as u32);
+
+        (0..size).for_each(|_| {
+            let mut pos = Ix2(rng.index(size), rng.index(size));
+            map[pos] = 0;
+            for height in 1..=9 {
+                let next = map.neighbours_4(pos).collect::<Vec<_>>();
+                if next.is_empty() { break }
+                pos = *rng.choose(&next);
+                map[pos] = height;
+            }
+        });
+
+        Some(map.to_string())
+    }
+
+    fn part_1(map: &Self::Input) -> Answer {
+        let total_unique_score = trailheads(map)
+            .map(|pos| check_paths(pos, map).iter().unique().count())
+            .checked_sum("the total score");
+
+        total_unique_score.into()
+    }
+
+    fn part_2(map: &Self::Input) -> Answer {
+        let total_score = trailheads(map)
+            .map(|pos| check_paths(pos, map).len())
+            .checked_sum("the total rating");
+
+        total_score.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day10;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day10>();
+    }
+}
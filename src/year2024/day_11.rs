@@ -0,0 +1,137 @@
+use itertools::Itertools;
+
+use crate::{checked::{self, Sum}, generate::Rng, memo::Memo, num::Digits, parse::{self, ParseError}, solution::{Answer, Example, Part, Solution}, trace::debug};
+
+const EXAMPLE: &str = "125 17";
+
+fn permute_stone(stone: u64) -> Vec<u64> {
+    if stone == 0 { vec![1] }
+    else if stone.digits().is_multiple_of(2) {
+        let (left, right) = stone.split_digits(stone.digits() / 2);
+        vec![left, right]
+    } else {
+        vec![checked::mul(stone, 2024, "an engraved number")]
+    }
+}
+
+fn permute_stones(stones: &mut Vec<u64>) {
+    *stones = stones.iter()
+        .copied()
+        .flat_map(|stone| {
+            permute_stone(stone)
+        })
+        .collect::<Vec<_>>()
+}
+
+/// How many stones a stone has become after some number of blinks.
+type Cache = Memo<(u64, usize), u64>;
+
+fn count_stone(stone: u64, blinks: usize, cache: &mut Cache) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+
+    cache.get_or_insert_with(&(stone, blinks), |cache| {
+        permute_stone(stone).into_iter()
+            .map(|stone| count_stone(stone, blinks - 1, cache))
+            .checked_sum("the number of stones")
+    })
+}
+
+fn count_stones(stones: &[u64], blinks: usize, cache: &mut Cache) -> u64 {
+    let total = stones.iter()
+        .map(|&stone| count_stone(stone, blinks, cache))
+        .checked_sum("the number of stones");
+
+    debug!("{}", cache.stats());
+    total
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("55312"), part_2: None },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.split_whitespace()
+            .map(parse::number::<u64>)
+            .collect()
+    }
+
+    /// `size` stones, a few of them single digits.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let stones = (0..size)
+            .map(|_| match rng.below(4) {
+                0 => rng.range(0..=9),
+                _ => rng.range(0..=9_999_999),
+            })
+            .join(" ");
+
+        Some(stones + "\n")
+    }
+
+    fn part_1(stones: &Self::Input) -> Answer {
+        let total_stones = count_stones(stones, 25, &mut Cache::new());
+
+        total_stones.into()
+    }
+
+    fn part_2(stones: &Self::Input) -> Answer {
+        let total_stones = count_stones(stones, 75, &mut Cache::new());
+
+        total_stones.into()
+    }
+
+    /// Blinks at every stone one by one. Only part 1, as 75 blinks makes far too many stones to keep.
+    fn reference(part: Part, stones: &Self::Input) -> Option<Answer> {
+        if part == Part::Two {
+            return None;
+        }
+
+        let mut stones = stones.clone();
+        (0..25).for_each(|_| permute_stones(&mut stones));
+
+        Some(stones.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checked::assert_overflows, generate::Rng, solution::{assert_examples, Solution}};
+
+    use super::{count_stones, permute_stones, Cache, Day11};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day11>();
+    }
+
+    #[test]
+    fn reports_huge_engravings() {
+        // 17 digits, so it's multiplied rather than split
+        let stones = Day11::parse("10000000000000000").unwrap();
+        assert_overflows("an engraved number", || Day11::part_1(&stones));
+    }
+
+    #[test]
+    fn counting_matches_blinking() {
+        let mut rng = Rng::new(11);
+        let mut cache = Cache::new();
+
+        for _ in 0..20 {
+            let input = Day11::generate(&mut rng, 3).unwrap();
+            let mut stones = Day11::parse(&input).unwrap();
+            // what's cached holds for any input, so there's no need to clear it in between
+            let counted = (0..=15).map(|blinks| count_stones(&stones, blinks, &mut cache)).collect::<Vec<_>>();
+
+            (0..=15).for_each(|blinks| {
+                assert_eq!(counted[blinks], stones.len() as u64, "{blinks} blinks at {input}");
+                permute_stones(&mut stones);
+            });
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::VecDeque;
+
+use ndarray::Ix2;
+
+use crate::{checked::{self, Sum}, generate::Rng, grid::Grid, parse::ParseError, solution::{Answer, Example, Solution}};
+
+const SMALL_EXAMPLE: &str = r"AAAA
+BBCD
+BBCC
+EEEC";
+
+const NESTED_EXAMPLE: &str = r"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+const E_EXAMPLE: &str = r"EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+
+const DIAGONAL_EXAMPLE: &str = r"AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA";
+
+const LARGE_EXAMPLE: &str = r"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: SMALL_EXAMPLE, part_1: Some("140"), part_2: Some("80") },
+        Example { input: NESTED_EXAMPLE, part_1: Some("772"), part_2: Some("436") },
+        Example { input: E_EXAMPLE, part_1: None, part_2: Some("236") },
+        Example { input: DIAGONAL_EXAMPLE, part_1: None, part_2: Some("368") },
+        Example { input: LARGE_EXAMPLE, part_1: Some("1930"), part_2: Some("1206") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |c| c.is_alphanumeric().then_some(c))
+    }
+
+    /// A `size` × `size` garden of regions grown around random seeds, with the odd stray plant
+    /// so that regions have holes in them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let plant = |rng: &mut Rng| (b'A' + rng.below(26) as u8) as char;
+        let seeds = (0..2 * size)
+            .map(|_| (Ix2(rng.index(size), rng.index(size)), plant(rng)))
+            .collect::<Vec<_>>();
+
+        let garden = Grid::from_fn(size, size, |pos| {
+            if rng.chance(0.05) {
+                return plant(rng);
+            }
+            seeds.iter()
+                .min_by_key(|(seed, _)| seed[0].abs_diff(pos[0]) + seed[1].abs_diff(pos[1]))
+                .expect("There's a seed for every row")
+                .1
+        });
+
+        Some(garden.to_string())
+    }
+
+    fn part_1(map: &Self::Input) -> Answer {
+        let mut visited_map = Grid::filled(map.rows(), map.cols(), false);
+        let mut prices: Vec<usize> = Default::default();
+        map.indexed_iter()
+            .for_each(|(seed_pos, &c)| {
+                if visited_map[seed_pos] { return }
+
+                let mut queue = VecDeque::new();
+                queue.push_back(seed_pos);
+
+                let mut area = 0;
+                let mut perimeter = 0;
+                while let Some(pos) = queue.pop_front() {
+                    if visited_map[pos] { continue }
+
+                    let len = queue.len();
+                    map.neighbours_4(pos)
+                        .filter(|adj_pos| map[*adj_pos] == c)
+                        .collect_into(&mut queue);
+                    let num_adjs = queue.len() - len;
+
+                    area += 1;
+                    perimeter += 4 - num_adjs;
+                    visited_map[pos] = true;
+                }
+
+                prices.push(checked::mul(area, perimeter, "a region's price"));
+            });
+
+        let total_price = prices.into_iter().checked_sum("the total price");
+        total_price.into()
+    }
+
+    fn part_2(map: &Self::Input) -> Answer {
+        let mut visited_map = Grid::filled(map.rows(), map.cols(), false);
+        let mut prices: Vec<usize> = Default::default();
+
+        map.indexed_iter()
+            .for_each(|(seed_pos, &c)| {
+                if visited_map[seed_pos] { return }
+
+                let mut queue = VecDeque::new();
+                queue.push_back(seed_pos);
+
+                let mut area = 0;
+                let mut interior_angles = 0;
+                let mut exterior_angles = 0;
+                while let Some(pos) = queue.pop_front() {
+                    if visited_map[pos] { continue }
+
+                    let adjs = map.neighbours_4(pos)
+                        .filter(|adj_pos| map[*adj_pos] == c)
+                        .collect::<Vec<_>>();
+                    queue.extend(&adjs);
+
+                    interior_angles += match adjs.len() {
+                        2 => {
+                            if adjs[0][0].abs_diff(adjs[1][0]) == 0
+                            || adjs[0][1].abs_diff(adjs[1][1]) == 0 {
+                                0
+                            } else { 1 }
+                        },
+                        1 => 2,
+                        0 => 4,
+                        _ => 0,
+                    };
+
+                    let num_diags = map.neighbours_8(pos)
+                        .filter(|diag_pos| diag_pos[0] != pos[0] && diag_pos[1] != pos[1])
+                        .filter(|diag_pos| map[*diag_pos] != c)
+                        .filter(|diag_pos| {
+                            map[Ix2(diag_pos[0], pos[1])] == c && map[Ix2(pos[0], diag_pos[1])] == c
+                        })
+                        .count();
+
+                    exterior_angles += num_diags;
+
+                    area += 1;
+                    visited_map[pos] = true;
+                }
+
+                // a region has as many sides as corners
+                prices.push(checked::mul(area, interior_angles + exterior_angles, "a region's price"));
+            });
+
+        let total_price = prices.into_iter().checked_sum("the total price");
+        total_price.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day12;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day12>();
+    }
+}
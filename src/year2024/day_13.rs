@@ -0,0 +1,228 @@
+use itertools::Itertools;
+use nalgebra::Vector2;
+use regex::{Captures, Regex};
+
+use crate::{checked::{self, Sum}, generate::Rng, num::{self, LinearSolution}, parse::{self, ParseError}, solution::{Answer, Example, Solution}, trace::debug};
+
+const EXAMPLE: &str = r"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a: Vector2<i64>,
+    button_b: Vector2<i64>,
+    target:   Vector2<i64>,
+}
+
+/// A costs three tokens a press, and B one. `None` if either is pressed a negative number of times.
+fn tokens(presses_a: i128, presses_b: i128) -> Option<u64> {
+    let (presses_a, presses_b) = (u64::try_from(presses_a).ok()?, u64::try_from(presses_b).ok()?);
+    Some(checked::add(checked::mul(3, presses_a, "the tokens for a prize"), presses_b, "the tokens for a prize"))
+}
+
+impl ClawMachine {
+    fn solve(&self) -> Option<u64> {
+        let [a, b, target] = [self.button_a, self.button_b, self.target].map(|v| [v[0], v[1]]);
+        match num::solve_linear(a, b, target) {
+            LinearSolution::One(presses_a, presses_b) => {
+                debug!("prize at ({}, {}) takes {presses_a} A and {presses_b} B presses", target[0], target[1]);
+                tokens(presses_a, presses_b)
+            },
+            LinearSolution::None => None,
+            LinearSolution::Parallel => self.solve_collinear(),
+        }
+    }
+
+    /// With both buttons moving the claw along the same line, there can be any number of ways to
+    /// the prize. The cost changes steadily from one to the next, so the cheapest is at one end.
+    fn solve_collinear(&self) -> Option<u64> {
+        // one axis is enough to say how far along the line the claw is
+        let axis = if self.button_a[0] != 0 || self.button_b[0] != 0 { 0 } else { 1 };
+        let (a, b, target) = (self.button_a[axis], self.button_b[axis], self.target[axis]);
+
+        let (presses_a, presses_b) = match (a, b) {
+            (0, 0) => (0, 0),
+            (_, 0) => ((target / a).into(), 0),
+            (0, _) => (0, (target / b).into()),
+            _ => {
+                let (gcd, x, y) = num::extended_gcd(a, b);
+                if target % gcd != 0 {
+                    return None;
+                }
+
+                // every way is (a0 + k * step_a, b0 - k * step_b), and neither can go below 0
+                let (a0, b0) = (x as i128 * (target / gcd) as i128, y as i128 * (target / gcd) as i128);
+                let (step_a, step_b) = ((b / gcd) as i128, (a / gcd) as i128);
+                let (k_min, k_max) = (-a0.div_euclid(step_a), b0.div_euclid(step_b));
+                if k_min > k_max {
+                    return None;
+                }
+
+                let k = if 3 * step_a > step_b { k_min } else { k_max };
+                (a0 + k * step_a, b0 - k * step_b)
+            },
+        };
+
+        debug!("prize at ({}, {}) takes {presses_a} A and {presses_b} B presses along the line", self.target[0], self.target[1]);
+        // checked in i128, as the presses needn't fit in an i64
+        let [a, b, target] = [self.button_a, self.button_b, self.target].map(|v| v.map(i128::from));
+        if a * presses_a + b * presses_b != target {
+            return None;
+        }
+
+        tokens(presses_a, presses_b)
+    }
+}
+
+impl TryFrom<Captures<'_>> for ClawMachine {
+    type Error = ParseError;
+
+    fn try_from(captures: Captures) -> Result<Self, Self::Error> {
+        let group = |name| captures.name(name).expect("Every group is required by the regex").as_str();
+
+        let a_x = parse::number(group("a_x"))?;
+        let a_y = parse::number(group("a_y"))?;
+        let b_x = parse::number(group("b_x"))?;
+        let b_y = parse::number(group("b_y"))?;
+        let t_x = parse::number(group("t_x"))?;
+        let t_y = parse::number(group("t_y"))?;
+
+        Ok(Self {
+            button_a: Vector2::new(a_x, a_y),
+            button_b: Vector2::new(b_x, b_y),
+            target:   Vector2::new(t_x, t_y),
+        })
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("480"), part_2: None },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"^Button A: X\+(?<a_x>\d+), Y\+(?<a_y>\d+)\nButton B: X\+(?<b_x>\d+), Y\+(?<b_y>\d+)\nPrize: X=(?<t_x>\d+), Y=(?<t_y>\d+)$")
+            .expect("Couldn't make regex");
+
+        input.trim()
+            .split("\n\n")
+            .map(|machine| {
+                re.captures(machine)
+                    .ok_or_else(|| ParseError::expected(machine, "a claw machine (two buttons and a prize)"))?
+                    .try_into()
+            })
+            .collect()
+    }
+
+    /// `size` claw machines. Most prizes can be won, and a few machines have collinear buttons.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let machines = (0..size)
+            .map(|_| {
+                let collinear = rng.chance(0.05);
+                let max = if collinear { 49 } else { 99 };
+                let a = (rng.range(10..=max), rng.range(10..=max));
+                let b = if collinear { (2 * a.0, 2 * a.1) } else { (rng.range(10..=99), rng.range(10..=99)) };
+
+                let target = if rng.chance(0.7) {
+                    let (presses_a, presses_b) = (rng.range(1..=100), rng.range(1..=100));
+                    (presses_a * a.0 + presses_b * b.0, presses_a * a.1 + presses_b * b.1)
+                } else {
+                    (rng.range(1000..=20000), rng.range(1000..=20000))
+                };
+
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    a.0, a.1, b.0, b.1, target.0, target.1,
+                )
+            })
+            .join("\n");
+
+        Some(machines)
+    }
+
+    fn part_1(machines: &Self::Input) -> Answer {
+        let total_cost = machines.iter()
+            .filter_map(|machine| {
+                machine.solve()
+            })
+            .checked_sum("the total tokens");
+
+        total_cost.into()
+    }
+
+    fn part_2(machines: &Self::Input) -> Answer {
+        let total_cost = machines.iter()
+            .cloned()
+            .filter_map(|mut machine| {
+                machine.target = machine.target.map(|t| checked::add(t, 10000000000000, "the prize's real position"));
+                machine.solve()
+            })
+            .checked_sum("the total tokens");
+
+        total_cost.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector2;
+
+    use crate::{checked::assert_overflows, solution::{assert_examples, Solution}};
+
+    use super::{ClawMachine, Day13};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day13>();
+    }
+
+    #[test]
+    fn solves_collinear_buttons() {
+        let solve = |button_a: (i64, i64), button_b: (i64, i64), target: (i64, i64)| ClawMachine {
+            button_a: Vector2::new(button_a.0, button_a.1),
+            button_b: Vector2::new(button_b.0, button_b.1),
+            target: Vector2::new(target.0, target.1),
+        }.solve();
+
+        // B goes further per token here, and A does there
+        assert_eq!(solve((10, 20), (20, 40), (100, 200)), Some(5));
+        assert_eq!(solve((40, 20), (4, 2), (124, 62)), Some(10));
+        assert_eq!(solve((0, 20), (0, 4), (0, 124)), Some(19));
+
+        // between the presses, off the line, and behind the claw
+        assert_eq!(solve((40, 20), (4, 2), (126, 63)), None);
+        assert_eq!(solve((40, 20), (4, 2), (124, 63)), None);
+        assert_eq!(solve((40, 20), (4, 2), (3, 1)), None);
+        assert_eq!(solve((40, 20), (0, 0), (120, 60)), Some(9));
+
+        // not pressing a button at all is fine whichever way the buttons point
+        assert_eq!(solve((10, 0), (0, 10), (0, 50)), Some(5));
+        assert_eq!(solve((10, 0), (0, 10), (-10, 50)), None);
+    }
+
+    #[test]
+    fn reports_prizes_too_far_away() {
+        let input = Day13::parse("Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=9000000000000000000, Y=1").unwrap();
+        assert_overflows("the tokens for a prize", || Day13::part_1(&input));
+
+        let input = Day13::parse("Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=9223372036854775000, Y=1").unwrap();
+        assert_overflows("the prize's real position", || Day13::part_2(&input));
+    }
+}
@@ -0,0 +1,252 @@
+use std::io;
+
+use itertools::Itertools;
+use nalgebra::Vector2;
+use ndarray::Array2;
+use regex::{Captures, Regex};
+
+use crate::{checked, direction::ToIx2, frames::{Frame, FrameSink}, generate::Rng, grid::Grid, num, parse::{self, ParseError}, solution::{Answer, Example, Part, Solution}, trace::debug};
+
+const EXAMPLE: &str = r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+pub struct Robot {
+    position: Vector2<u32>,
+    velocity: Vector2<i32>,
+}
+
+impl Robot {
+    fn position_after(&self, steps: u32, map_size: Vector2<u32>) -> Vector2<u32> {
+        // any u32 plus any u32 times any i32 fits in an i64
+        let mut new_position = self.position.cast::<i64>() + i64::from(steps) * self.velocity.cast::<i64>();
+        new_position[0] = new_position[0].rem_euclid(map_size[0] as i64);
+        new_position[1] = new_position[1].rem_euclid(map_size[1] as i64);
+        new_position.try_cast().expect("negative?")
+    }
+}
+
+impl TryFrom<Captures<'_>> for Robot {
+    type Error = ParseError;
+
+    fn try_from(captures: Captures) -> Result<Self, Self::Error> {
+        let group = |name| captures.name(name).expect("Every group is required by the regex").as_str();
+
+        let p_x = parse::number(group("p_x"))?;
+        let p_y = parse::number(group("p_y"))?;
+        let v_x = parse::number(group("v_x"))?;
+        let v_y = parse::number(group("v_y"))?;
+
+        Ok(Self {
+            position: Vector2::new(p_x, p_y),
+            velocity: Vector2::new(v_x, v_y),
+        })
+    }
+}
+
+fn positions_to_map(positions: &[Vector2<u32>], map_size: Vector2<u32>) -> Array2<u32> {
+    let mut map: Array2<u32> = Array2::zeros((map_size[1] as usize, map_size[0] as usize));
+    positions.iter()
+        .for_each(|pos| {
+            map[pos.to_ix2().expect("Position doesn't fit on the map")] += 1;
+        });
+    
+    map
+}
+
+/// The longest line of robots in each row, added up. Works from the positions rather than a map, as it's
+/// tried on every second of the period.
+fn christmas_tree_heuristic(positions: &mut [Vector2<u32>]) -> usize {
+    positions.sort_unstable_by_key(|position| (position[1], position[0]));
+
+    positions.chunk_by(|a, b| a[1] == b[1])
+        .map(|row| {
+            let (longest, _) = row.iter()
+                .map(|position| position[0])
+                .dedup()
+                .tuple_windows()
+                .fold((1, 1), |(longest, current), (a, b)| {
+                    let current = if b == a + 1 { current + 1 } else { 1 };
+                    (longest.max(current), current)
+                });
+
+            longest
+        })
+        .sum()
+}
+
+/// How many robots are on each tile, or `.` for none.
+fn frame(map: &Array2<u32>) -> Frame {
+    let (rows, cols) = map.dim();
+    Grid::from_fn(rows, cols, |pos| match map[pos] {
+        0 => '.',
+        robots => char::from_digit(robots.min(9), 10).expect("Robots are counted up to 9"),
+    })
+}
+
+fn positions_after(robots: &[Robot], seconds: u32, map_size: Vector2<u32>) -> Vec<Vector2<u32>> {
+    robots.iter()
+        .map(|robot| {
+            robot.position_after(seconds, map_size)
+        })
+        .collect()
+}
+
+fn map_after(robots: &[Robot], seconds: u32, map_size: Vector2<u32>) -> Array2<u32> {
+    positions_to_map(&positions_after(robots, seconds, map_size), map_size)
+}
+
+/// The number of seconds until the robots look most like a Christmas tree, and the map at that point.
+///
+/// This scores every second of one period rather than using the Chinese remainder theorem to put together
+/// the seconds when the columns and the rows are each most bunched up, as the heuristic looks along whole
+/// rows and so can't be split by axis.
+fn likely_tree(robots: &[Robot], map_size: Vector2<u32>) -> (u32, Array2<u32>) {
+    // each robot is back where it started once both its row and its column have come round
+    let period = num::lcm(map_size[0], map_size[1]).expect("The map is small");
+
+    let (likely_tree, _) = (0..period)
+        .map(|i| (i, christmas_tree_heuristic(&mut positions_after(robots, i, map_size))))
+        .max_by_key(|(_, h)| *h)
+        .expect("No maximum found");
+
+    (likely_tree, map_after(robots, likely_tree, map_size))
+}
+
+/// The number of robots in each quadrant, multiplied together.
+fn safety_factor(quadrants: [usize; 4]) -> usize {
+    quadrants.into_iter()
+        .fold(1, |product, robots| checked::mul(product, robots, "the safety factor"))
+}
+
+const MAP_SIZE: Vector2<u32> = Vector2::new(101, 103);
+const EXAMPLE_MAP_SIZE: Vector2<u32> = Vector2::new(11, 7);
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<Robot>, Vector2<u32>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("12"), part_2: None },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"^p=(?<p_x>-?\d+),(?<p_y>-?\d+) v=(?<v_x>-?\d+),(?<v_y>-?\d+)$")
+            .expect("Couldn't make regex");
+
+        let robots = input.lines()
+            .map(|line| {
+                re.captures(line)
+                    .ok_or_else(|| ParseError::expected(line, "a robot like `p=0,4 v=3,-3`"))?
+                    .try_into()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((robots, MAP_SIZE))
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        let (robots, _) = Self::parse(input)?;
+        Ok((robots, EXAMPLE_MAP_SIZE))
+    }
+
+    /// `size` robots anywhere on the map, moving up to 99 tiles a second each way.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (width, height) = (MAP_SIZE[0] as i64, MAP_SIZE[1] as i64);
+        let robots = (0..size)
+            .map(|_| {
+                let position = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+                let velocity = (rng.range(-99..=99), rng.range(-99..=99));
+                format!("p={},{} v={},{}\n", position.0, position.1, velocity.0, velocity.1)
+            })
+            .collect();
+
+        Some(robots)
+    }
+
+    fn part_1(&(ref robots, map_size): &Self::Input) -> Answer {
+        let positions = robots.iter()
+            .map(|robot| {
+                robot.position_after(100, map_size)
+            })
+            .collect::<Vec<_>>();
+
+        let map = positions_to_map(&positions, map_size);
+        debug!("after 100 seconds:\n{}", frame(&map));
+        
+        let quadrants = positions.into_iter()
+            .filter_map(|position| {
+                let x_pivot = map_size[0] / 2;
+                let y_pivot = map_size[1] / 2;
+                let x_half = (position[0] != x_pivot).then_some(position[0] < x_pivot)?;
+                let y_half = (position[1] != y_pivot).then_some(position[1] < y_pivot)?;
+
+                Some((x_half, y_half))
+            })
+            .counts();
+
+        // a quadrant without any robots still counts, making it perfectly safe
+        let quadrants = [(false, false), (false, true), (true, false), (true, true)]
+            .map(|quadrant| quadrants.get(&quadrant).copied().unwrap_or(0));
+
+        safety_factor(quadrants).into()
+    }
+
+    fn part_2(&(ref robots, map_size): &Self::Input) -> Answer {
+        let (likely_tree, map) = likely_tree(robots, map_size);
+        debug!("most like a tree after {likely_tree} seconds:\n{}", frame(&map));
+
+        likely_tree.into()
+    }
+
+    /// The robots for the first 100 seconds in part 1, or until they make the tree in part 2.
+    fn animate(part: Part, &(ref robots, map_size): &Self::Input, sink: &mut dyn FrameSink) -> Option<io::Result<()>> {
+        let seconds = match part {
+            Part::One => 100,
+            Part::Two => likely_tree(robots, map_size).0,
+        };
+
+        Some((0..=seconds).try_for_each(|i| sink.push(&frame(&map_after(robots, i, map_size)))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector2;
+
+    use crate::{checked::assert_overflows, solution::{assert_examples, Solution}};
+
+    use super::{safety_factor, Day14, Robot, MAP_SIZE};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day14>();
+    }
+
+    #[test]
+    fn reports_an_enormous_safety_factor() {
+        // it takes 2^16 robots in each quadrant
+        assert_eq!(safety_factor([1 << 16, 1 << 16, 1 << 16, (1 << 16) - 1]), (1 << 48) * ((1 << 16) - 1));
+        assert_overflows("the safety factor", || safety_factor([1 << 16; 4]));
+    }
+
+    #[test]
+    fn empty_quadrants_are_safe() {
+        // robots standing still in three corners, and one on the middle row
+        let robots = [(0, 0), (100, 0), (0, 102), (100, 51)].into_iter()
+            .map(|(x, y)| Robot { position: Vector2::new(x, y), velocity: Vector2::zeros() })
+            .collect();
+
+        assert_eq!(Day14::part_1(&(robots, MAP_SIZE)), 0.into());
+    }
+}
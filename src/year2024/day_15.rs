@@ -0,0 +1,284 @@
+use std::io;
+
+use ndarray::Ix2;
+
+use crate::{checked::{self, Sum}, direction::Direction, frames::{Frame, FrameSink}, generate::Rng, grid::Grid, parse::ParseError, solution::{Answer, Example, Part, Solution}};
+
+const SMALL_EXAMPLE: &str = r"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+const LARGE_EXAMPLE: &str = r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+type Position = Ix2;
+
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    #[default]
+    Empty,
+    Box,
+    Wall,
+    BoxLeft,
+    BoxRight,
+}
+
+fn from_input(input: &str) -> Result<(State, Vec<Direction>), ParseError> {
+    let (map, instructions) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let (map, [robot]) = Grid::parse_with_markers(map, ['@'], |c| match c {
+        '.' | '@' => Some(Tile::Empty),
+        'O' => Some(Tile::Box),
+        '#' => Some(Tile::Wall),
+        _ => None
+    })?;
+
+    let instructions = instructions.lines()
+        .flat_map(|l| {
+            l.char_indices().map(|(i, c)| {
+                Direction::try_from(c)
+                    .map_err(|_| ParseError::expected(&l[i..i + c.len_utf8()], "a move (`^`, `>`, `v` or `<`)"))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((State { map, robot }, instructions))
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    map: Grid<Tile>,
+    robot: Position,
+}
+
+impl State {
+    fn widen_tiles(&mut self) {
+        let mut new_map = Grid::filled(self.map.rows(), self.map.cols() * 2, Tile::Empty);
+
+        self.map.indexed_iter()
+            .for_each(|(pos, tile)| {
+                let (row, col) = (pos[0], pos[1]);
+                match tile {
+                    Tile::Box => {
+                        new_map[Ix2(row, col * 2)] = Tile::BoxLeft;
+                        new_map[Ix2(row, col * 2 + 1)] = Tile::BoxRight;
+                    },
+                    t => {
+                        new_map[Ix2(row, col * 2)] = *t;
+                        new_map[Ix2(row, col * 2 + 1)] = *t;
+                    },
+                }
+            });
+        
+        self.map = new_map;
+        self.robot = Ix2(self.robot[0], self.robot[1] * 2);
+    }
+
+    fn move_direction(&mut self, direction: Direction) {
+        let next = self.map.step(self.robot, direction);
+        if self.can_move_box(next, direction, true) {
+            let next = next.expect("Robot can only move onto the map");
+            self.move_box(next, direction, true);
+            self.robot = next;
+        }
+    }
+
+    fn can_move_box(&mut self, position: Option<Position>, direction: Direction, check_other_half: bool) -> bool {
+        let Some(position) = position else { return false };
+
+        match self.map[position] {
+            Tile::Wall => false,
+            Tile::Empty => true,
+            Tile::Box => self.can_move_box(self.map.step(position, direction), direction, true),
+            Tile::BoxLeft => {
+                let move_in_direction = self.can_move_box(self.map.step(position, direction), direction, true);
+                let move_other_half = if !direction.is_horizontal() && check_other_half {
+                    self.can_move_box(self.map.step(position, Direction::Right), direction, false)
+                } else { true };
+
+                move_in_direction && move_other_half
+            },
+            Tile::BoxRight => {
+                let move_in_direction = self.can_move_box(self.map.step(position, direction), direction, true);
+                let move_other_half = if !direction.is_horizontal() && check_other_half {
+                    self.can_move_box(self.map.step(position, Direction::Left), direction, false)
+                } else { true };
+
+                move_in_direction && move_other_half
+            },
+        }
+    }
+
+    fn step(&self, position: Position, direction: Direction) -> Position {
+        self.map.step(position, direction).expect("Boxes can't be pushed off the map")
+    }
+
+    fn move_box(&mut self, position: Position, direction: Direction, move_other_half: bool) {
+        match self.map[position] {
+            Tile::Wall => panic!("Shouldn't move wall!"),
+            Tile::Empty => (),
+            Tile::Box => {
+                let next = self.step(position, direction);
+                self.move_box(next, direction, true);
+                self.map[next] = Tile::Box;
+            },
+            Tile::BoxLeft => {
+                let next = self.step(position, direction);
+                self.move_box(next, direction, true);
+                if !direction.is_horizontal() && move_other_half {
+                    self.move_box(self.step(position, Direction::Right), direction, false);
+                }
+                self.map[next] = Tile::BoxLeft;
+            },
+            Tile::BoxRight => {
+                let next = self.step(position, direction);
+                self.move_box(next, direction, true);
+                if !direction.is_horizontal() && move_other_half {
+                    self.move_box(self.step(position, Direction::Left), direction, false);
+                }
+                self.map[next] = Tile::BoxRight;
+            },
+        }
+        self.map[position] = Tile::Empty;
+    }
+
+    fn sum_gps_coords(&self) -> u64 {
+        self.map.indexed_iter()
+            .filter_map(|(pos, tile)| {
+                if *tile != Tile::Box && *tile != Tile::BoxLeft { None }
+                else {
+                    Some(checked::add(checked::mul(pos[0] as u64, 100, "a GPS coordinate"), pos[1] as u64, "a GPS coordinate"))
+                }
+            })
+            .checked_sum("the total of the GPS coordinates")
+    }
+
+    fn frame(&self) -> Frame {
+        Grid::from_fn(self.map.rows(), self.map.cols(), |pos| {
+            if self.robot == pos { '@' }
+            else {
+                match self.map[pos] {
+                    Tile::Empty => '.',
+                    Tile::Box => 'O',
+                    Tile::Wall => '#',
+                    Tile::BoxLeft => '[',
+                    Tile::BoxRight => ']',
+                }
+            }
+        })
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (State, Vec<Direction>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: SMALL_EXAMPLE, part_1: Some("2028"), part_2: None },
+        Example { input: LARGE_EXAMPLE, part_1: Some("10092"), part_2: Some("9021") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        from_input(input)
+    }
+
+    /// A `size` × `size` walled warehouse of boxes and walls, and `10 * size` moves.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(3);
+        let robot = Ix2(1 + rng.index(size - 2), 1 + rng.index(size - 2));
+        let map = Grid::from_fn(size, size, |pos| {
+            let edge = pos[0] == 0 || pos[1] == 0 || pos[0] == size - 1 || pos[1] == size - 1;
+            if pos == robot { '@' }
+            else if edge || rng.chance(0.08) { '#' }
+            else if rng.chance(0.3) { 'O' }
+            else { '.' }
+        });
+
+        let moves = (0..10 * size)
+            .map(|_| char::from(*rng.choose(&Direction::ALL)))
+            .collect::<Vec<_>>();
+        let moves = moves.chunks(70)
+            .map(|line| line.iter().collect::<String>() + "\n")
+            .collect::<String>();
+
+        Some(map.to_string() + "\n" + &moves)
+    }
+
+    fn part_1((state, instructions): &Self::Input) -> Answer {
+        let mut state = state.clone();
+
+        instructions.iter()
+            .for_each(|dir| {
+                state.move_direction(*dir);
+            });
+
+        state.sum_gps_coords().into()
+    }
+
+    fn part_2((state, instructions): &Self::Input) -> Answer {
+        let mut state = state.clone();
+        state.widen_tiles();
+
+        instructions.iter()
+            .for_each(|dir| {
+                state.move_direction(*dir);
+            });
+
+        state.sum_gps_coords().into()
+    }
+
+    /// The robot's moves around the warehouse, widened for part 2.
+    fn animate(part: Part, (state, instructions): &Self::Input, sink: &mut dyn FrameSink) -> Option<io::Result<()>> {
+        let mut state = state.clone();
+        if part == Part::Two {
+            state.widen_tiles();
+        }
+
+        let mut frames = std::iter::once(state.frame())
+            .chain(instructions.iter().map(|dir| {
+                state.move_direction(*dir);
+                state.frame()
+            }));
+
+        Some(frames.try_for_each(|frame| sink.push(&frame)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day15;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day15>();
+    }
+}
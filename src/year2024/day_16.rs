@@ -0,0 +1,229 @@
+use std::io;
+
+use fxhash::FxHashSet;
+use itertools::Itertools;
+use ndarray::Ix2;
+
+use crate::{direction::Direction, frames::{Frame, FrameSink}, generate::{self, Rng}, grid::Grid, parse::ParseError, search::{self, Search}, solution::{Answer, Example, Part, Solution}};
+
+const EXAMPLE: &str = r"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+pub struct Map {
+    map: Grid<bool>,
+    start_pos: Ix2,
+    end_pos: Ix2,
+}
+
+/// A reindeer's position and the way it's facing.
+type State = (Ix2, Direction);
+
+impl Map {
+    fn get(&self, pos: Ix2) -> bool {
+        self.map[pos]
+    }
+
+    /// Turning costs 1000, stepping forward costs 1.
+    fn next_states(&self, (position, direction): State) -> impl Iterator<Item = (State, u64)> + use<'_> {
+        let ahead = self.map.step(position, direction)
+            .filter(|&position| !self.get(position))
+            .map(|ahead| ((ahead, direction), 1));
+
+        [
+            ((position, direction.turn_right()), 1000),
+            ((position, direction.turn_left()), 1000),
+        ].into_iter().chain(ahead)
+    }
+
+    /// The lowest score to reach the end, and every end state that achieves it, or `None` if it can't be reached.
+    fn min_score(&self, search: &Search<State>) -> Option<(u64, Vec<State>)> {
+        let end_states = Direction::ALL.into_iter()
+            .map(|dir| (self.end_pos, dir))
+            .filter_map(|state| Some((search.distance(state)?, state)))
+            .min_set_by_key(|&(score, _)| score);
+
+        let score = end_states.first()?.0;
+        Some((score, end_states.into_iter().map(|(_, state)| state).collect()))
+    }
+
+    fn search(&self) -> Search<State> {
+        search::dijkstra([(self.start_pos, Direction::Right)], |state| self.next_states(state))
+    }
+
+    /// The maze with the tiles the search has `reached` marked `X`, the reindeer at the newest one,
+    /// and `O` on the `best` tiles.
+    fn frame(&self, reached: &Grid<bool>, reindeer: Option<State>, best: &FxHashSet<Ix2>) -> Frame {
+        Grid::from_fn(self.map.rows(), self.map.cols(), |pos| match reindeer {
+            Some((at, direction)) if at == pos => direction.into(),
+            _ if pos == self.start_pos => 'S',
+            _ if pos == self.end_pos => 'E',
+            _ if best.contains(&pos) => 'O',
+            _ if self.get(pos) => '#',
+            _ if reached[pos] => 'X',
+            _ => '.',
+        })
+    }
+
+    /// Replays the search one newly reached tile at a time, in the order it settled them, up to the best score.
+    /// Then marks the tiles of one best path, or of all of them, if the end was reached at all.
+    fn explore(&self, all_best_paths: bool, sink: &mut dyn FrameSink) -> io::Result<()> {
+        let search = self.search();
+        let (score, end_states) = self.min_score(&search).unwrap_or((u64::MAX, Vec::new()));
+        let mut reached = Grid::filled(self.map.rows(), self.map.cols(), false);
+
+        search.distances().iter()
+            .filter(|&(_, &distance)| distance <= score)
+            .sorted_by_key(|&(&state, &distance)| (distance, state.0[0], state.0[1]))
+            .try_for_each(|(&state, _)| {
+                if reached[state.0] {
+                    return Ok(());
+                }
+                reached[state.0] = true;
+                sink.push(&self.frame(&reached, Some(state), &FxHashSet::default()))
+            })?;
+
+        let best = if all_best_paths {
+            search.nodes_on_paths_to(end_states)
+        } else {
+            end_states.first()
+                .and_then(|&end_state| search.path_to(end_state))
+                .into_iter()
+                .flatten()
+                .collect()
+        };
+        let best = best.into_iter().map(|(position, _)| position).collect();
+
+        sink.push(&self.frame(&reached, None, &best))
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (map, [start_pos, end_pos]) = Grid::parse_with_markers(value, ['S', 'E'], |c| match c {
+            '.' | 'S' | 'E' => Some(false),
+            '#' => Some(true),
+            _ => None
+        })?;
+
+        Ok(Self {
+            map,
+            start_pos,
+            end_pos,
+        })
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("7036"), part_2: Some("45") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.try_into()
+    }
+
+    /// A maze of `size` × `size` cells with some extra walls knocked through,
+    /// so that there are several routes to the end and often more than one best one.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // the start and end need a cell each
+        let size = size.max(2);
+        let mut walls = generate::maze(rng, size, size);
+        let last = 2 * size - 1;
+
+        // walls between two cells, rather than the border or the corners between walls
+        let between_cells = walls.indexed_iter()
+            .filter(|&(pos, &wall)| wall && pos[0] % 2 != pos[1] % 2)
+            .filter(|&(pos, _)| (1..=last).contains(&pos[0]) && (1..=last).contains(&pos[1]))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        between_cells.into_iter()
+            .filter(|_| rng.chance(0.1))
+            .for_each(|pos| walls[pos] = false);
+
+        let (start, end) = (Ix2(last, 1), Ix2(1, last));
+        let map = walls.display_with(|pos, &wall| {
+            if pos == start { 'S' }
+            else if pos == end { 'E' }
+            else if wall { '#' }
+            else { '.' }
+        });
+
+        Some(map.to_string())
+    }
+
+    fn part_1(map: &Self::Input) -> Answer {
+        map.min_score(&map.search())
+            .map(|(score, _)| score)
+            .into()
+    }
+
+    fn part_2(map: &Self::Input) -> Answer {
+        let search = map.search();
+        map.min_score(&search)
+            .map(|(_, end_states)| {
+                search.nodes_on_paths_to(end_states)
+                    .into_iter()
+                    .map(|(position, _)| position)
+                    .unique()
+                    .count()
+            })
+            .into()
+    }
+
+    /// The search spreading out from the start, then a best path in part 1 or all of them in part 2.
+    fn animate(part: Part, map: &Self::Input, sink: &mut dyn FrameSink) -> Option<io::Result<()>> {
+        Some(map.explore(part == Part::Two, sink))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Answer, Part, Solution};
+
+    use super::{Day16, EXAMPLE};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day16>();
+    }
+
+    #[test]
+    fn walled_off_ends_have_no_answer() {
+        let map = Day16::parse("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(Day16::part_1(&map), Answer::None);
+        assert_eq!(Day16::part_2(&map), Answer::None);
+        Day16::animate(Part::Two, &map, &mut Vec::new()).unwrap().unwrap();
+    }
+
+    #[test]
+    fn animation_ends_on_the_best_tiles() {
+        let map = Day16::parse(EXAMPLE).unwrap();
+        let mut frames = Vec::new();
+        Day16::animate(Part::Two, &map, &mut frames).unwrap().unwrap();
+
+        let (last, searching) = frames.split_last().unwrap();
+        let reindeer = |tile: &char| "^>v<".contains(*tile);
+        assert!(searching.iter().all(|frame| frame.iter().filter(|tile| reindeer(tile)).count() == 1));
+        // the start and end are drawn over two of the 45
+        assert_eq!(last.iter().filter(|&&tile| tile == 'O').count() + 2, 45);
+    }
+}
@@ -0,0 +1,286 @@
+use std::rc::Rc;
+
+use itertools::Itertools;
+use num_traits::FromPrimitive;
+use num_derive::FromPrimitive;
+use regex::Regex;
+
+use crate::{checked, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Solution}, trace::{debug, trace}};
+
+const EXAMPLE: &str = r"Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+const QUINE_EXAMPLE: &str = r"Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+#[derive(FromPrimitive, Debug, Clone, Copy)]
+enum Opcode {
+    Adv = 0, // A <- trunc(A / pow(2, combo_op))
+    Bxl = 1, // B <- B ^ lit_op
+    Bst = 2, // B <- combo_op % 8
+    Jnz = 3, // IP <- (A == 0 ? IP : lit_op)
+    Bxc = 4, // B <- B ^ C
+    Out = 5, // output combo_op % 8
+    Bdv = 6, // B <- trunc(A / pow(2, combo_op))
+    Cdv = 7, // C <- trunc(A / pow(2, combo_op))
+}
+
+enum CycleResult {
+    NoOutput,
+    Output(u8),
+    Done,
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    a: isize,
+    b: isize,
+    c: isize,
+    ip: usize,
+
+    instructions: Rc<[u8]>,
+}
+
+impl State {
+    /// A machine with the given registers, about to run `instructions` from the start.
+    pub fn new(a: isize, b: isize, c: isize, instructions: &[u8]) -> Self {
+        Self { a, b, c, ip: 0, instructions: instructions.into() }
+    }
+
+    fn combo_op(&self, operand: u8) -> isize {
+        match operand {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => unreachable!("combo operand 7 is rejected when parsing")
+        }
+    }
+
+    /// `trunc(A / pow(2, combo_op))`, as a shift so that it's 0 rather than an overflow when the power is too big.
+    /// The registers are never negative, so shifting rounds the same way as dividing.
+    fn divide_a(&self, operand: u8) -> isize {
+        u32::try_from(self.combo_op(operand)).ok()
+            .and_then(|power| self.a.checked_shr(power))
+            .unwrap_or(0)
+    }
+
+    fn cycle(&mut self) -> CycleResult {
+        let opcode = self.instructions[self.ip];
+        let operand = self.instructions[self.ip + 1];
+
+        let mut did_jump = false;
+        let mut output = None;
+
+        use Opcode::*;
+        match Opcode::from_u8(opcode) {
+            Some(Adv) => {
+                self.a = self.divide_a(operand)
+            }, // A <- trunc(A / pow(2, combo_op))
+            Some(Bxl) => {
+                self.b ^= operand as isize
+            }, // B <- B ^ lit_op
+            Some(Bst) => {
+                self.b = self.combo_op(operand) % 8
+            }, // B <- combo_op % 8
+            Some(Jnz) => {
+                if self.a != 0 {
+                    self.ip = operand as usize;
+                    did_jump = true
+                }
+            }, // IP <- (A == 0 ? IP : lit_op)
+            Some(Bxc) => {
+                self.b ^= self.c
+            }, // B <- B ^ C
+            Some(Out) => {
+                output.replace((self.combo_op(operand) % 8) as u8);
+            }, // output combo_op % 8
+            Some(Bdv) => {
+                self.b = self.divide_a(operand)
+            }, // B <- trunc(A / pow(2, combo_op))
+            Some(Cdv) => {
+                self.c = self.divide_a(operand)
+            }, // C <- trunc(A / pow(2, combo_op))
+            None => panic!("invalid opcode"),
+        };
+
+        trace!("{: >2}: {:?}, {operand} // A: {: >8} // B: {: >8} // C: {: >8}", self.ip, Opcode::from_u8(opcode), self.a, self.b, self.c);
+
+        if !did_jump {
+            self.ip += 2;
+        }
+
+        // a jump to the last number leaves an opcode without its operand, which halts too
+        if self.ip + 1 >= self.instructions.len() {
+            return CycleResult::Done;
+        }
+
+        output.map_or(CycleResult::NoOutput, CycleResult::Output)
+    }
+
+    /// Runs until the program halts, returning everything it output.
+    pub fn run(&mut self) -> Vec<u8> {
+        let mut output: Vec<u8> = Default::default();
+        loop {
+            match self.cycle() {
+                CycleResult::Done => break,
+                CycleResult::Output(o) => output.push(o),
+                _ => ()
+            }        
+        }
+
+        output
+    }
+}
+
+impl TryFrom<&str> for State {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let regex = Regex::new(r"^Register A: (?<a>\d+)\nRegister B: (?<b>\d+)\nRegister C: (?<c>\d+)\n\nProgram: (?<program>\d+(?:,\d+)*)$").unwrap();
+        let value = value.trim();
+        let captures = regex.captures(value)
+            .ok_or_else(|| ParseError::expected(value, "three registers and a program"))?;
+        let group = |name| captures.name(name).expect("Every group is required by the regex").as_str();
+
+        let numbers = group("program").split(',').collect::<Vec<_>>();
+        if numbers.len() % 2 == 1 {
+            return Err(ParseError::expected(parse::end_of(value), "an operand for the last opcode"));
+        }
+
+        let program = numbers.iter()
+            .map(|s| {
+                let instruction = parse::number::<u8>(s)?;
+                (instruction < 8).then_some(instruction)
+                    .ok_or_else(|| ParseError::expected(s, "a 3-bit number"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // only the literal operands of bxl, jnz and bxc can be 7
+        let combo_7 = program.chunks(2)
+            .position(|instruction| matches!(instruction, [0 | 2 | 5 | 6 | 7, 7]));
+        if let Some(i) = combo_7 {
+            return Err(ParseError::expected(numbers[i * 2 + 1], "a combo operand from 0 to 6"));
+        }
+
+        Ok(Self::new(
+            parse::number(group("a"))?,
+            parse::number(group("b"))?,
+            parse::number(group("c"))?,
+            &program,
+        ))
+    }
+}
+
+/// The octal digits of `a`, lowest first.
+fn octets(a: isize) -> String {
+    if a == 0 { return "0 ".to_owned(); }
+    let mut octets = String::new();
+    let mut curr = a;
+    while curr > 0 {
+        let n = curr % 8;
+        curr >>= 3;
+        octets += &format!("{n} ");
+    }
+    octets
+}
+
+/// The values of register A, one more octal digit on from `current_a`, for which the program outputs `target`.
+///
+/// This relies on the program being a loop that outputs once and shifts A right by three bits each time
+/// round, like every puzzle input, so that each digit of A decides one more number of output from the end.
+fn prog_find(state: &State, target: &[u8], current_a: isize) -> Vec<isize> {
+    debug!("looking for {target:?} after A = {current_a}");
+    let attempted_a = checked::mul(current_a, 8, "register A");
+    (0..8)
+        .filter_map(|i| {
+            let a = checked::add(attempted_a, i, "register A");
+            let out = State { a, ..state.clone() }.run();
+            trace!("{}// {out:?}", octets(a));
+            (out == target).then_some(a)
+        })
+        .collect::<Vec<_>>()
+}
+
+fn prog_recurse(state: &State, found: usize, current_a: isize) -> Option<isize> {
+    let program = &state.instructions;
+    if found == program.len() { return Some(current_a) }
+
+    let options = prog_find(state, &program[program.len() - found - 1..], current_a);
+    debug!("found options: {options:?}");
+    options.iter()
+        .find_map(|option| prog_recurse(state, found + 1, *option))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = State;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("4,6,3,5,6,3,5,2,1,0"), part_2: None },
+        Example { input: QUINE_EXAMPLE, part_1: Some("5,7,3,0"), part_2: Some("117440") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.try_into()
+    }
+
+    /// A program shaped like the puzzle's but with its own constants, and a register A
+    /// of `size` octal digits (at most 20, so that it fits).
+    ///
+    /// Most constants don't allow any register A that reproduces the program, so part 2 has no answer.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let a = (0..size.clamp(1, 20))
+            .fold(0, |a, i| a * 8 + rng.range(if i == 0 { 1 } else { 0 }..=7));
+
+        // bst A, bxl _, cdv B, bxl _, bxc _, out B, adv 3, jnz 0
+        let program = [2, 4, 1, rng.range(0..=7), 7, 5, 1, rng.range(0..=7), 4, rng.range(0..=7), 5, 5, 0, 3, 3, 0];
+
+        Some(format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program.iter().join(",")))
+    }
+
+    fn part_1(state: &Self::Input) -> Answer {
+        let mut state = state.clone();
+
+        state.run().into()
+    }
+
+    fn part_2(state: &Self::Input) -> Answer {
+        prog_recurse(state, 0, 0).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Solution};
+
+    use super::Day17;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day17>();
+    }
+
+    #[test]
+    fn rejects_programs_it_cant_run() {
+        let registers = "Register A: 7\nRegister B: 0\nRegister C: 0\n\nProgram: ";
+
+        // bxl can take a 7, out can't
+        let input = format!("{registers}1,7,5,7,3,0");
+        let err = Day17::parse(&input).unwrap_err().in_day(17, &input);
+        assert_eq!(err.to_string(), "day 17, line 5, column 16: expected a combo operand from 0 to 6, found `7`");
+
+        let err = Day17::parse(&format!("{registers}0,3,5")).unwrap_err();
+        assert_eq!(err.to_string(), "expected an operand for the last opcode, found the end of the input");
+    }
+}
@@ -0,0 +1,170 @@
+use ndarray::Ix2;
+
+use crate::{checked, generate::Rng, grid::Grid, parse::{self, ParseError}, search, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+const SIZE: usize = 71;
+const FALLEN: usize = 1024;
+const EXAMPLE_SIZE: usize = 7;
+const EXAMPLE_FALLEN: usize = 12;
+
+/// The fewest steps from the top-left corner to the bottom-right one.
+fn min_score(map: &Grid<bool>) -> Option<u64> {
+    let exit = Ix2(map.rows() - 1, map.cols() - 1);
+    let manhattan = |pos: Ix2| checked::add(exit[0].abs_diff(pos[0]), exit[1].abs_diff(pos[1]), "the distance to the exit") as u64;
+
+    search::astar(
+        Ix2(0, 0),
+        |pos| map.neighbours_4(pos).filter(|&next| !map[next]).map(|next| (next, 1)),
+        manhattan,
+        |pos| pos == exit,
+    ).map(|(cost, _)| cost)
+}
+
+fn parse_bytes(input: &str, size: usize) -> Result<Vec<Ix2>, ParseError> {
+    input.lines()
+        .map(|l| {
+            let (x, y) = parse::split_once(l, ",", "a position like `5,4`")?;
+            let (x, y) = (parse::number::<usize>(x)?, parse::number::<usize>(y)?);
+            if x >= size || y >= size {
+                return Err(ParseError::expected(l, "a position within the memory space"));
+            }
+
+            Ok(Ix2(y, x))
+        })
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    /// The falling bytes, the side of the memory space, and how many bytes have fallen by part 1
+    type Input = (Vec<Ix2>, usize, usize);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("22"), part_2: Some("6,1") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_bytes(input, SIZE)?, SIZE, FALLEN))
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_bytes(input, EXAMPLE_SIZE)?, EXAMPLE_SIZE, EXAMPLE_FALLEN))
+    }
+
+    /// The first `size` bytes to fall, never on the start or the exit. The first kilobyte
+    /// always leaves a way through, as part 1 expects.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut bytes = (0..SIZE)
+            .flat_map(|x| (0..SIZE).map(move |y| (x, y)))
+            .filter(|&pos| pos != (0, 0) && pos != (SIZE - 1, SIZE - 1))
+            .collect::<Vec<_>>();
+
+        loop {
+            rng.shuffle(&mut bytes);
+
+            let mut map = Grid::filled(SIZE, SIZE, false);
+            bytes.iter()
+                .take(FALLEN)
+                .for_each(|&(x, y)| map[Ix2(y, x)] = true);
+            if min_score(&map).is_some() { break }
+        }
+
+        let bytes = bytes.into_iter()
+            .take(size)
+            .map(|(x, y)| format!("{x},{y}\n"))
+            .collect();
+
+        Some(bytes)
+    }
+
+    fn part_1((positions, size, fallen): &Self::Input) -> Answer {
+        let mut map = Grid::filled(*size, *size, false);
+
+        positions
+            .iter()
+            .take(*fallen)
+            .for_each(|&pos| {
+                map[pos] = true;
+            });
+
+        min_score(&map).into()
+    }
+
+    fn part_2((positions, size, _): &Self::Input) -> Answer {
+        let map = Grid::filled(*size, *size, false);
+
+        let is_passable = |steps: usize| {
+            let mut map = map.clone();
+
+            positions.iter()
+                .take(steps)
+                .for_each(|&pos| {
+                    map[pos] = true;
+                });
+            
+            min_score(&map).is_some()
+        };
+
+        // the exit is cut off by the last of however many bytes first make it impassable
+        let steps = (1..=positions.len()).collect::<Vec<_>>();
+        let partition_point = steps.partition_point(|&steps| is_passable(steps));
+
+        positions.get(partition_point)
+            .map(|first_impassable| vec![first_impassable[1], first_impassable[0]])
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Answer, Solution};
+
+    use super::{Day18, EXAMPLE};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day18>();
+    }
+
+    #[test]
+    fn no_answer_without_a_cut_off_exit() {
+        // the first byte lands on the exit, and without it nothing blocks the way
+        let blocked = Day18::parse_example("6,6\n1,1").unwrap();
+        assert_eq!(Day18::part_1(&blocked), Answer::None);
+        assert_eq!(Day18::part_2(&blocked).to_string(), "6,6");
+
+        let open = Day18::parse_example("1,1").unwrap();
+        assert_eq!(Day18::part_2(&open), Answer::None);
+        assert_eq!(Day18::part_2(&Day18::parse_example("").unwrap()), Answer::None);
+
+        let example = EXAMPLE.lines().take(20).collect::<Vec<_>>().join("\n");
+        assert_eq!(Day18::part_2(&Day18::parse_example(&example).unwrap()), Answer::None);
+    }
+}
@@ -0,0 +1,228 @@
+use itertools::Itertools;
+use trie_rs::Trie;
+
+use crate::{checked::Sum, generate::Rng, memo::Memo, parse::ParseError, solution::{Answer, Example, Solution}, trace::debug};
+
+const EXAMPLE: &str = r"r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Color {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl TryFrom<char> for Color {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'w' => Ok(Color::White),
+            'u' => Ok(Color::Blue),
+            'b' => Ok(Color::Black),
+            'r' => Ok(Color::Red),
+            'g' => Ok(Color::Green),
+            _ => Err(())
+        }
+    }
+}
+
+impl From<Color> for char {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::White => 'w',
+            Color::Blue => 'u',
+            Color::Black => 'b',
+            Color::Red => 'r',
+            Color::Green => 'g',
+        }
+    }
+}
+
+impl FromIterator<Color> for String {
+    fn from_iter<T: IntoIterator<Item = Color>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(char::from)
+            .collect()
+    }
+}
+
+impl<'a> FromIterator<&'a Color> for String {
+    fn from_iter<T: IntoIterator<Item = &'a Color>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(|col| char::from(*col))
+            .collect()
+    }
+}
+
+fn colors(stripes: &str) -> Result<Vec<Color>, ParseError> {
+    stripes.char_indices()
+        .map(|(i, c)| {
+            Color::try_from(c)
+                .map_err(|_| ParseError::expected(&stripes[i..i + c.len_utf8()], "a colour (`w`, `u`, `b`, `r` or `g`)"))
+        })
+        .collect()
+}
+
+/// The towel patterns on a line like `r, wr, b`.
+fn patterns(line: &str) -> Result<Vec<Vec<Color>>, ParseError> {
+    let mut start = 0;
+    line.split(", ")
+        .map(|pattern| {
+            let rest = &line[start..];
+            start += pattern.len() + ", ".len();
+
+            // the trie can't hold an empty pattern
+            if pattern.is_empty() {
+                return Err(ParseError::expected(rest, "a towel pattern"));
+            }
+            colors(pattern)
+        })
+        .collect()
+}
+
+fn from_input(input: &str) -> Result<(Trie<Color>, Vec<Vec<Color>>), ParseError> {
+    let mut lines = input.lines();
+
+    let patterns: Trie<Color> = lines
+        .take_while_ref(|l| !l.is_empty())
+        .map(patterns)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let designs: Vec<Vec<Color>> = lines.skip(1)
+        .map(colors)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((patterns, designs))
+}
+
+/// How many ways there are to make a design, shared between designs that end the same way.
+type Cache = Memo<Vec<Color>, usize>;
+
+fn try_make_design(patterns: &Trie<Color>, design: &[Color], cache: &mut Cache) -> usize {
+    if design.is_empty() { return 1 }
+
+    cache.get_or_insert_with(design, |cache| {
+        let mut prefixes: Vec<Vec<Color>> = patterns.common_prefix_search(design).collect();
+        prefixes.sort_by_key(|p| std::cmp::Reverse(p.len()));
+
+        prefixes.iter()
+            .map(|prefix| try_make_design(patterns, &design[prefix.len()..], cache))
+            .checked_sum("the number of ways to make a design")
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Trie<Color>, Vec<Vec<Color>>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("6"), part_2: Some("16") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        from_input(input)
+    }
+
+    /// `size` towel patterns and `size` designs, about half of which are made from the patterns.
+    ///
+    /// One colour never comes as a towel on its own, so that not every design is possible.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const COLORS: [Color; 5] = [Color::White, Color::Blue, Color::Black, Color::Red, Color::Green];
+
+        let stripes = |rng: &mut Rng, len: i64| (0..len).map(|_| *rng.choose(&COLORS)).collect::<Vec<_>>();
+        let missing = *rng.choose(&COLORS);
+
+        let patterns = (0..size)
+            .map(|_| {
+                let len = rng.range(1..=8);
+                stripes(rng, len)
+            })
+            .filter(|pattern| pattern != &[missing])
+            .unique()
+            .collect::<Vec<_>>();
+
+        let designs = (0..size)
+            .map(|_| {
+                let len = rng.range(20..=60);
+                if patterns.is_empty() || rng.chance(0.5) {
+                    return stripes(rng, len);
+                }
+
+                let mut design = Vec::new();
+                while (design.len() as i64) < len {
+                    design.extend(rng.choose(&patterns));
+                }
+                design
+            })
+            .map(|design| design.iter().collect::<String>() + "\n")
+            .collect::<String>();
+
+        let patterns = patterns.iter()
+            .map(|pattern| pattern.iter().collect::<String>())
+            .join(", ");
+
+        Some(patterns + "\n\n" + &designs)
+    }
+
+    fn part_1((patterns, designs): &Self::Input) -> Answer {
+        let mut cache = Cache::new();
+        let num_possible: usize = designs.iter()
+            .filter(|design| try_make_design(patterns, design, &mut cache) > 0)
+            .count();
+        debug!("{}", cache.stats());
+
+        num_possible.into()
+    }
+
+    fn part_2((patterns, designs): &Self::Input) -> Answer {
+        let mut cache = Cache::new();
+        let ways_possible = designs.iter()
+            .map(|design| try_make_design(patterns, design, &mut cache))
+            .checked_sum("the number of ways to make every design");
+        debug!("{}", cache.stats());
+
+        ways_possible.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checked::assert_overflows, solution::{assert_examples, Solution}};
+
+    use super::Day19;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day19>();
+    }
+
+    #[test]
+    fn rejects_empty_patterns() {
+        let input = "r, , b\n\nrb";
+        let err = Day19::parse(input).unwrap_err().in_day(19, input);
+        assert_eq!(err.to_string(), "day 19, line 1, column 4: expected a towel pattern, found `, b`");
+    }
+
+    #[test]
+    fn reports_too_many_ways() {
+        // the ways to make n white stripes from one and two at a time are the Fibonacci numbers
+        let input = Day19::parse(&format!("w, ww\n\n{}", "w".repeat(100))).unwrap();
+        assert_overflows("the number of ways to make a design", || Day19::part_2(&input));
+    }
+}
@@ -0,0 +1,115 @@
+use itertools::Itertools;
+
+use crate::{checked, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+fn is_safe(report: &[i32]) -> bool {
+    // in i64, as two levels far enough apart don't have a difference that fits in an i32
+    let diffs = report.iter().zip(report.iter().skip(1)).map(|(&a, &b)| i64::from(a) - i64::from(b));
+
+    let is_monotonic = diffs.clone().all(|d| d <= 0) || diffs.clone().all(|d| d >= 0);
+    let is_within_range = diffs.clone().all(|d| d.abs() >= 1 && d.abs() <= 3);
+
+    is_monotonic && is_within_range
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("2"), part_2: Some("4") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                // convert each line to a vec of integers, splitting by whitespace
+                line.split_whitespace()
+                    .map(parse::number::<i32>)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect()
+    }
+
+    /// `size` reports of five to eight levels, about half with one bad level.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let reports = (0..size)
+            .map(|_| {
+                let step = if rng.chance(0.5) { 1 } else { -1 };
+                let mut level = rng.range(25..=75);
+                let mut levels = (0..rng.range(5..=8))
+                    .map(|_| {
+                        level += step * rng.range(1..=3);
+                        level
+                    })
+                    .collect::<Vec<_>>();
+
+                // which the dampener in part 2 may or may not be able to remove
+                if rng.chance(0.5) {
+                    let i = rng.index(levels.len());
+                    levels[i] = (levels[i] + rng.range(-4..=4)).max(1);
+                }
+
+                levels.iter().join(" ") + "\n"
+            })
+            .collect();
+
+        Some(reports)
+    }
+
+    fn part_1(reports: &Self::Input) -> Answer {
+        let safe_reports = reports.iter().filter(|report| is_safe(report)).count();
+
+        safe_reports.into()
+    }
+
+    fn part_2(reports: &Self::Input) -> Answer {
+        let safe_reports = reports.iter().filter(|report| is_safe(report)).count();
+
+        let newly_safe_reports = reports
+            .iter()
+            .filter(|report| !is_safe(report))
+            .filter(|report| {
+                report
+                    .iter()
+                    .enumerate()
+                    .map(|(i, _)| {
+                        let mut modified = (*report).clone();
+                        modified.remove(i);
+                        modified
+                    })
+                    .any(|report| is_safe(&report))
+            })
+            .count();
+
+        checked::add(newly_safe_reports, safe_reports, "the safe reports").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Solution};
+
+    use super::Day2;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day2>();
+    }
+
+    #[test]
+    fn compares_levels_at_either_end_of_the_range() {
+        let reports = Day2::parse("2147483647 -2147483648\n-2147483648 -2147483647").unwrap();
+        assert_eq!(Day2::part_1(&reports), 1.into());
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::Display;
+use ndarray::Ix2;
+
+use crate::{checked, generate::{self, Rng}, grid::Grid, parse::ParseError, search::{self, Search}, solution::{Answer, Example, Solution}, trace::trace};
+
+const EXAMPLE: &str = r"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+fn manhattan(a: Ix2, b: Ix2) -> usize {
+    a[0].abs_diff(b[0]) + a[1].abs_diff(b[1])
+}
+
+pub struct Map {
+    map: Grid<bool>,
+    start_pos: Ix2,
+    end_pos: Ix2,
+}
+
+impl Map {
+    fn get(&self, pos: Ix2) -> bool {
+        self.map[pos]
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let map = self.map.display_with(|pos, &wall| {
+            if self.start_pos == pos { 'S' }
+            else if self.end_pos == pos { 'E' }
+            else if wall { '#' }
+            else { '.' }
+        });
+
+        write!(f, "{map}")
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (map, [start_pos, end_pos]) = Grid::parse_with_markers(value, ['S', 'E'], |c| match c {
+            '.' | 'S' | 'E' => Some(false),
+            '#' => Some(true),
+            _ => None
+        })?;
+
+        Ok(Self {
+            map,
+            start_pos,
+            end_pos,
+        })
+    }
+}
+
+/// Searches the track from the start and back from the end, or `None` if the end can't be reached.
+fn race_track(map: &Map) -> Option<(Search<Ix2>, Search<Ix2>)> {
+    let track = |pos| map.map.neighbours_4(pos).filter(|&next| !map.get(next));
+
+    let from_start = search::bfs([map.start_pos], track);
+    from_start.distance(map.end_pos)?;
+    let to_end = search::bfs([map.end_pos], track);
+
+    Some((from_start, to_end))
+}
+
+/// Every cheat of up to `cheat_distance` picoseconds that makes the race shorter, with how much it saves.
+///
+/// A cheat can start anywhere the start reaches and finish anywhere that reaches the end, so the track is
+/// free to branch: what it saves is measured against the fastest race without cheating.
+fn cheat_savings<'a>(map: &'a Map, (from_start, to_end): &'a (Search<Ix2>, Search<Ix2>), cheat_distance: usize) -> impl Iterator<Item = (Ix2, Ix2, u64)> + use<'a> {
+    let fastest = from_start.distance(map.end_pos).expect("The race track reaches the end");
+    let radius = cheat_distance as isize;
+    let offsets = (-radius..=radius)
+        .flat_map(move |d_row| {
+            let width = radius - d_row.abs();
+            (-width..=width).map(move |d_col| [d_row, d_col])
+        });
+
+    from_start.distances().iter()
+        .flat_map(move |(&start_pos, &before)| {
+            offsets.clone().filter_map(move |delta| {
+                let end_pos = map.map.offset(start_pos, delta)?;
+                let after = to_end.distance(end_pos)?;
+                let length = checked::add(before, manhattan(start_pos, end_pos) as u64, "a race's length");
+                let length = checked::add(length, after, "a race's length");
+
+                fastest.checked_sub(length)
+                    .filter(|&saving| saving > 0)
+                    .map(|saving| (start_pos, end_pos, saving))
+            })
+        })
+}
+
+const MIN_SAVING: u64 = 100;
+const EXAMPLE_MIN_SAVING: u64 = 50;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    /// The map, and the smallest saving worth counting
+    type Input = (Map, u64);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("1"), part_2: Some("285") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((input.try_into()?, MIN_SAVING))
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((input.try_into()?, EXAMPLE_MIN_SAVING))
+    }
+
+    /// A maze of `size` × `size` cells with about `size` extra walls knocked through, so that the track
+    /// branches and loops back on itself.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // the start and end need a cell each
+        let size = size.max(2);
+        let mut walls = generate::maze(rng, size, size);
+
+        // the wall to the right of or below a cell, unless that's the outside wall
+        (0..size).for_each(|_| {
+            let (row, col) = (rng.index(size), rng.index(size));
+            if rng.chance(0.5) {
+                if col + 1 < size { walls[Ix2(2 * row + 1, 2 * col + 2)] = false }
+            } else if row + 1 < size {
+                walls[Ix2(2 * row + 2, 2 * col + 1)] = false
+            }
+        });
+
+        let mut cell = || Ix2(2 * rng.index(size) + 1, 2 * rng.index(size) + 1);
+        let start_pos = cell();
+        let end_pos = std::iter::repeat_with(cell)
+            .find(|&pos| pos != start_pos)
+            .expect("There are at least two cells");
+
+        let map = Map { map: walls, start_pos, end_pos };
+        Some(map.to_string())
+    }
+
+    fn part_1(&(ref map, min_saving): &Self::Input) -> Answer {
+        race_track(map)
+            .map(|track| {
+                cheat_savings(map, &track, 2)
+                    .inspect(|s| trace!("{s:?}"))
+                    .filter(|(_, _, saving)| *saving >= min_saving)
+                    .count()
+            })
+            .into()
+    }
+
+    fn part_2(&(ref map, min_saving): &Self::Input) -> Answer {
+        race_track(map)
+            .map(|track| {
+                cheat_savings(map, &track, 20)
+                    .inspect(|s| trace!("{s:?}"))
+                    .filter(|(_, _, saving)| *saving >= min_saving)
+                    .count()
+            })
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Answer, Solution};
+
+    use super::{Day20, Map};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day20>();
+    }
+
+    #[test]
+    fn cheats_on_branching_tracks() {
+        let map = |text: &str| Map::try_from(text).unwrap();
+        let branching = map("#########\n#S..#..E#\n#.#.#.#.#\n#.#...#.#\n#.#####.#\n#.......#\n#########");
+        let input = (branching, 2);
+        assert_eq!(Day20::part_1(&input), 2.into());
+        assert_eq!(Day20::part_2(&input), 25.into());
+
+        let input = (map("#####\n#S#E#\n#####"), 1);
+        assert_eq!(Day20::part_1(&input), Answer::None);
+    }
+}
@@ -0,0 +1,236 @@
+use std::iter::{once, repeat_n};
+
+use itertools::Itertools;
+use ndarray::Ix2;
+
+use crate::{checked::{self, Sum}, generate::Rng, memo::Memo, parse::{self, ParseError}, solution::{Answer, Example, Solution}, trace::{debug, info}};
+
+const EXAMPLE: &str = r"029A
+980A
+179A
+456A
+379A";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Operation {
+    MoveUp,
+    MoveRight,
+    MoveDown,
+    MoveLeft,
+    Push,
+}
+
+impl From<Operation> for Ix2 {
+    fn from(value: Operation) -> Self {
+        match value {
+            Operation::MoveUp => Ix2(0, 1),
+            Operation::MoveRight => Ix2(1, 2),
+            Operation::MoveDown => Ix2(1, 1),
+            Operation::MoveLeft => Ix2(1, 0),
+            Operation::Push => Ix2(0, 2),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Button(char);
+
+impl From<Button> for Ix2 {
+    fn from(value: Button) -> Self {
+        match value {
+            Button('7') => Ix2(0, 0),
+            Button('8') => Ix2(0, 1),
+            Button('9') => Ix2(0, 2),
+            Button('4') => Ix2(1, 0),
+            Button('5') => Ix2(1, 1),
+            Button('6') => Ix2(1, 2),
+            Button('1') => Ix2(2, 0),
+            Button('2') => Ix2(2, 1),
+            Button('3') => Ix2(2, 2),
+            Button('0') => Ix2(3, 1),
+            Button('A') => Ix2(3, 2),
+            _ => unreachable!("codes are checked when parsing"),
+        }
+    }
+}
+
+fn numeric_shortest_path(from: Ix2, to: Ix2) -> Vec<impl Iterator<Item = Operation> + Clone> {
+    let y_diff = to[0].checked_signed_diff(from[0]).unwrap();
+    let x_diff = to[1].checked_signed_diff(from[1]).unwrap();
+
+    let x_steps = 
+        if x_diff >= 0 {
+            repeat_n(Operation::MoveRight, x_diff.unsigned_abs())
+        } else {
+            repeat_n(Operation::MoveLeft, x_diff.unsigned_abs())
+        };
+    let y_steps = 
+        if y_diff >= 0 {
+            repeat_n(Operation::MoveDown, y_diff.unsigned_abs())
+        } else {
+            repeat_n(Operation::MoveUp, y_diff.unsigned_abs())
+        };
+
+    if Ix2(from[0].checked_add_signed(y_diff).unwrap(), from[1]) == Ix2(3, 0) {
+        vec![x_steps.chain(y_steps)]
+    } else if Ix2(from[0], from[1].checked_add_signed(x_diff).unwrap()) == Ix2(3, 0) {
+        vec![y_steps.chain(x_steps)]
+    } else if y_diff != 0 && x_diff != 0 {
+        vec![x_steps.clone().chain(y_steps.clone()), y_steps.chain(x_steps)]
+    } else {
+        vec![y_steps.chain(x_steps)]
+    }
+}
+
+fn directional_shortest_path(from: Ix2, to: Ix2) -> Vec<impl Iterator<Item = Operation> + Clone> {
+    let y_diff = to[0].checked_signed_diff(from[0]).unwrap();
+    let x_diff = to[1].checked_signed_diff(from[1]).unwrap();
+
+    let x_steps = 
+        if x_diff >= 0 {
+            repeat_n(Operation::MoveRight, x_diff.unsigned_abs())
+        } else {
+            repeat_n(Operation::MoveLeft, x_diff.unsigned_abs())
+        };
+    let y_steps = 
+        if y_diff >= 0 {
+            repeat_n(Operation::MoveDown, y_diff.unsigned_abs())
+        } else {
+            repeat_n(Operation::MoveUp, y_diff.unsigned_abs())
+        };
+
+    if Ix2(from[0].checked_add_signed(y_diff).unwrap(), from[1]) == Ix2(0, 0) {
+        vec![x_steps.chain(y_steps)]
+    } else if Ix2(from[0], from[1].checked_add_signed(x_diff).unwrap()) == Ix2(0, 0) {
+        vec![y_steps.chain(x_steps)]
+    } else if y_diff != 0 && x_diff != 0 {
+        vec![x_steps.clone().chain(y_steps.clone()), y_steps.chain(x_steps)]
+    } else {
+        vec![y_steps.chain(x_steps)]
+    }
+}
+
+/// The cost of moving between two buttons on a directional keypad and pushing the second, with so many robots
+/// above it.
+type Cache = Memo<(Operation, Operation, usize), u64>;
+
+fn keypad_cost(from: Operation, to: Operation, robots: usize, cache: &mut Cache) -> u64 {
+    // we're pushing the buttons ourselves
+    if robots == 0 { return 1 }
+
+    cache.get_or_insert_with(&(from, to, robots), |cache| {
+        directional_shortest_path(from.into(), to.into())
+            .into_iter()
+            .map(|path| path_cost(path, robots - 1, cache))
+            .min().unwrap()
+    })
+}
+
+/// Every path starts and ends with the arm over `A`, as it has to push it at the end.
+fn path_cost(path: impl Iterator<Item = Operation>, robots: usize, cache: &mut Cache) -> u64 {
+    let mut current_op = Operation::Push;
+    path.chain(once(Operation::Push)).map(|op| {
+        let cost = keypad_cost(current_op, op, robots, cache);
+        current_op = op;
+        cost
+    }).checked_sum("the presses for a path")
+}
+
+fn compute_numeric_costs(path: impl IntoIterator<Item = (Button, Button)>, robots: usize, cache: &mut Cache) -> u64 {
+    path.into_iter()
+        .map(|(from, to)| {
+            numeric_shortest_path(from.into(), to.into())
+                .into_iter()
+                .map(|path| path_cost(path, robots, cache))
+                .min().unwrap()
+        })
+        .checked_sum("the presses for a code")
+}
+
+/// A door code, e.g. `029A`.
+#[derive(Debug)]
+pub struct Code {
+    buttons: Vec<Button>,
+    /// The code without its `A`, e.g. 29
+    numeric: u64,
+}
+
+fn total_complexity(codes: &[Code], robots: usize) -> u64 {
+    let mut cache = Cache::new();
+
+    let complexity = codes.iter()
+        .map(|code| {
+            let path = once(Button('A')).chain(code.buttons.iter().copied()).tuple_windows();
+            let num_steps = compute_numeric_costs(path, robots, &mut cache);
+
+            info!("{}: {num_steps} x {}", code.buttons.iter().map(|b| b.0).collect::<String>(), code.numeric);
+            checked::mul(num_steps, code.numeric, "the complexity of a code")
+        })
+        .checked_sum("the total complexity");
+
+    debug!("{}", cache.stats());
+    complexity
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("126384"), part_2: None },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines()
+            .map(|code| {
+                let digits = code.strip_suffix('A')
+                    .filter(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+                    .ok_or_else(|| ParseError::expected(code, "a code like `029A`"))?;
+
+                Ok(Code { buttons: code.chars().map(Button).collect(), numeric: parse::number(digits)? })
+            })
+            .collect()
+    }
+
+    /// `size` door codes of three digits and an `A`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let codes = (0..size)
+            .map(|_| format!("{:03}A\n", rng.range(0..=999)))
+            .collect();
+
+        Some(codes)
+    }
+
+    fn part_1(codes: &Self::Input) -> Answer {
+        total_complexity(codes, 2).into()
+    }
+
+    fn part_2(codes: &Self::Input) -> Answer {
+        total_complexity(codes, 25).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checked::assert_overflows, solution::{assert_examples, Solution}};
+
+    use super::Day21;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day21>();
+    }
+
+    #[test]
+    fn rejects_codes_it_cant_type_or_score() {
+        assert_eq!(Day21::parse("029A\n0B9A").unwrap_err().text, "0B9A");
+        assert_eq!(Day21::parse("029A\n123456789012345678901A").unwrap_err().text, "123456789012345678901");
+    }
+
+    #[test]
+    fn reports_huge_complexities() {
+        let codes = Day21::parse("18446744073709551615A").unwrap();
+        assert_overflows("the complexity of a code", || Day21::part_1(&codes));
+    }
+}
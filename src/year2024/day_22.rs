@@ -0,0 +1,185 @@
+use fxhash::{FxHashMap, FxHashSet};
+use itertools::{iproduct, Itertools};
+use num_traits::ToPrimitive;
+
+use crate::{checked::{self, Sum}, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Part, Solution}, trace::trace};
+
+const EXAMPLE: &str = r"1
+10
+100
+2024";
+
+const PRICES_EXAMPLE: &str = r"1
+2
+3
+2024";
+
+#[inline]
+const fn mix(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+#[inline]
+const fn prune(a: u64) -> u64 {
+    a % 16777216
+}
+
+#[inline]
+const fn hash_cycle(a: u64) -> u64 {
+    // only the bits that survive pruning matter to the first step, so pruning first gives the same secret
+    // without a huge one overflowing
+    let a = prune(a);
+    let a = prune(mix(a, a * 64));
+    let a = prune(mix(a, a / 32));
+    prune(mix(a, a * 2048))
+}
+
+fn hash(a: u64, cycles: usize) -> u64 {
+    (0..cycles)
+        .fold(a, |a, _| hash_cycle(a))
+}
+
+fn all_hashes(a: u64) -> impl Iterator<Item = u64> {
+    let mut current_hash = a;
+    (0..)
+        .map(move |_| {
+            current_hash = hash_cycle(current_hash);
+            current_hash
+        })
+}
+
+fn all_prices(a: u64) -> impl Iterator<Item = u8> {
+    all_hashes(a)
+        .map(|a| (a % 10).to_u8().unwrap())
+}
+
+fn price_differences(a: u64) -> impl Iterator<Item = (u8, i8)> {
+    all_prices(a)
+        .tuple_windows()
+        .map(|(a, b)| (b, a.checked_signed_diff(b).unwrap()))
+}
+
+fn all_price_differences(seeds: impl IntoIterator<Item = u64>) -> FxHashMap<[i8; 4], u64> {
+    let mut cache = FxHashMap::default();
+    seeds.into_iter()
+        .for_each(|seed| {
+            let mut already_seen = FxHashSet::default();
+            price_differences(seed).take(2000)
+                .tuple_windows()
+                .filter_map(move |(a, b, c, d)| {
+                    let changes = [a.1, b.1, c.1, d.1];
+                    if !already_seen.contains(&changes) {
+                        already_seen.insert(changes);
+                        trace!("{seed}: {changes:?} {}", d.0);
+                        Some((changes, d.0))
+                    } else { None }
+                })
+                .for_each(|(key, cost)| {
+                    let entry = cache.entry(key).or_insert(0);
+                    *entry = checked::add(*entry, cost as u64, "the bananas for a sequence");
+                });
+        });
+
+    cache
+}
+
+fn change_combinations() -> impl Iterator<Item = [i8; 4]> {
+    iproduct!(
+        -9..=9,
+        -9..=9,
+        -9..=9,
+        -9..=9,
+    )
+        .map(|(a, b, c, d)| [a, b, c, d])
+}
+
+/// The price a buyer sells at for each sequence of four changes, taken straight from their 2001 prices.
+fn first_sale_prices(seed: u64) -> FxHashMap<[i8; 4], u64> {
+    let prices = std::iter::once(seed)
+        .chain(all_hashes(seed))
+        .take(2001)
+        .map(|a| (a % 10) as i8)
+        .collect::<Vec<_>>();
+
+    // the latest sequences go in first, so each one ends up with the price of its first appearance
+    prices.windows(5).rev()
+        .map(|w| ([w[1] - w[0], w[2] - w[1], w[3] - w[2], w[4] - w[3]], w[4] as u64))
+        .collect()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<u64>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("37327623"), part_2: None },
+        Example { input: PRICES_EXAMPLE, part_1: None, part_2: Some("23") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines()
+            .map(parse::number)
+            .try_collect()
+    }
+
+    /// `size` buyers' initial secret numbers.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let seeds = (0..size)
+            .map(|_| format!("{}\n", rng.range(1..=16777215)))
+            .collect();
+
+        Some(seeds)
+    }
+
+    fn part_1(seeds: &Self::Input) -> Answer {
+        let sum_hashes = seeds.iter()
+            .map(|&seed| hash(seed, 2000))
+            .checked_sum("the total of the secret numbers");
+        sum_hashes.into()
+    }
+
+    fn part_2(seeds: &Self::Input) -> Answer {
+        let price_diffs = all_price_differences(seeds.iter().copied());
+        let most_bananas = price_diffs
+            .into_iter()
+            .max_by_key(|(_, cost)| *cost).unwrap()
+            .1;
+        most_bananas.into()
+    }
+
+    /// Tries every sequence of four changes against every buyer.
+    fn reference(part: Part, seeds: &Self::Input) -> Option<Answer> {
+        if part == Part::One {
+            return None;
+        }
+
+        let buyers = seeds.iter()
+            .map(|&seed| first_sale_prices(seed))
+            .collect::<Vec<_>>();
+        let most_bananas = change_combinations()
+            .map(|changes| buyers.iter().filter_map(|prices| prices.get(&changes).copied()).checked_sum("the bananas for a sequence"))
+            .max()
+            .expect("There are sequences to try");
+
+        Some(most_bananas.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::{hash, prune, Day22};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day22>();
+    }
+
+    #[test]
+    fn hashes_huge_secrets() {
+        assert_eq!(hash(u64::MAX, 10), hash(prune(u64::MAX), 10));
+        assert_eq!(hash(123 + (1 << 40), 10), hash(123, 10));
+    }
+}
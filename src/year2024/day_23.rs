@@ -0,0 +1,202 @@
+use std::iter::once;
+
+use fxhash::{FxHashMap, FxHashSet};
+use itertools::Itertools;
+use petgraph::{graph::{NodeIndex, UnGraph}, Graph, Undirected};
+
+use crate::{generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Solution}, trace::{info, trace}};
+
+const EXAMPLE: &str = r"kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+type Computer = [char; 2];
+type Network = UnGraph<Computer, ()>;
+
+fn computer(name: &str) -> Result<Computer, ParseError> {
+    let mut chars = name.chars();
+    match (chars.next_chunk(), chars.next()) {
+        (Ok(computer), None) => Ok(computer),
+        _ => Err(ParseError::expected(name, "a two-letter computer name")),
+    }
+}
+
+type NodeSet = FxHashSet<NodeIndex>;
+pub fn maximal_cliques(graph: &Network, clique: NodeSet, mut candidates: NodeSet, mut excluded: NodeSet) -> Box<dyn Iterator<Item = NodeSet> + '_> {
+    if candidates.is_empty() && excluded.is_empty() {
+        Box::new(once(clique.into_iter().collect()))
+    } else {
+        Box::new(std::iter::from_fn(move || {
+            if let Some(&v) = candidates.iter().next() {
+                let mut new_clique = clique.clone();
+                new_clique.insert(v);
+
+                let neighbour_set: NodeSet = graph.neighbors(v).collect();
+                let max_cliques = maximal_cliques(graph, new_clique, candidates.intersection(&neighbour_set).copied().collect(), excluded.intersection(&neighbour_set).copied().collect());
+                candidates.remove(&v);
+                excluded.insert(v);
+                Some(max_cliques)
+            } else { None }
+        }).flatten())
+    }
+}
+
+pub fn all_maximal_cliques(graph: &Network) -> Box<dyn Iterator<Item = NodeSet> + '_> {
+    let candidates = graph.node_indices().collect();
+    maximal_cliques(graph, Default::default(), candidates, Default::default())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Network;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("7"), part_2: Some("co,de,ka,ta") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut network = Network::default();
+        let mut node_indices = FxHashMap::default();
+        input
+            .lines()
+            .map(|line| {
+                let (start, end) = parse::split_once(line, "-", "a connection like `kh-tc`")?;
+                Ok((computer(start)?, computer(end)?))
+            })
+            .try_for_each(|connection: Result<(Computer, Computer), ParseError>| {
+                let (start, end) = connection?;
+                let s = *node_indices.entry(start).or_insert_with(|| network.add_node(start));
+                let e = *node_indices.entry(end).or_insert_with(|| network.add_node(end));
+
+                network.add_edge(s, e, ());
+                Ok(())
+            })?;
+
+        Ok(network)
+    }
+
+    /// A network of `size` computers (at most 676, the two-letter names there are),
+    /// each connected to a dozen or so others, with a LAN party of 13 hidden in it.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut names = ('a'..='z')
+            .flat_map(|a| ('a'..='z').map(move |b| format!("{a}{b}")))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        names.truncate(size);
+        let n = names.len();
+
+        let mut connections = FxHashSet::default();
+        (0..n).for_each(|i| {
+            (0..6).for_each(|_| {
+                let j = rng.index(n);
+                if i != j {
+                    connections.insert((i.min(j), i.max(j)));
+                }
+            });
+        });
+        // the names are shuffled, so the party may as well be the first 13
+        (0..n.min(13)).tuple_combinations().for_each(|connection| {
+            connections.insert(connection);
+        });
+
+        let mut connections = connections.into_iter().sorted().collect::<Vec<_>>();
+        rng.shuffle(&mut connections);
+
+        let connections = connections.into_iter()
+            .map(|(i, j)| {
+                let (a, b) = if rng.chance(0.5) { (i, j) } else { (j, i) };
+                format!("{}-{}\n", names[a], names[b])
+            })
+            .collect();
+
+        Some(connections)
+    }
+
+    fn part_1(network: &Self::Input) -> Answer {
+        let subgraph: Graph<(), (), Undirected, usize> = Graph::from_edges([(0, 1), (1, 2), (2, 0)]);
+
+        let num_cycles = petgraph::algo::subgraph_isomorphisms_iter(
+            &&subgraph,
+            &&network,
+            &mut |_, _| true,
+            &mut |_, _| true,
+        )
+            // there are none when the network has fewer than three computers
+            .into_iter()
+            .flatten()
+            .map(|mut computers| {
+                computers.sort();
+                computers.into_iter()
+                    .map(|n| network[NodeIndex::new(n)])
+                    .next_chunk::<3>()
+                    .unwrap()
+            })
+            .filter(|computers| computers.iter().any(|c| c[0] == 't'))
+            .unique()
+            .count();
+        num_cycles.into()
+    }
+
+    fn part_2(network: &Self::Input) -> Answer {
+        let Some(maximum_clique) = all_maximal_cliques(network)
+            .inspect(|clique| trace!("{clique:?}"))
+            .max_by_key(|clique| clique.len())
+        else {
+            return Answer::None;
+        };
+        info!("maximum clique: {maximum_clique:?}");
+        let mut computers = maximum_clique.into_iter().map(|node| network.node_weight(node).unwrap()).collect::<Vec<_>>();
+        computers.sort_by(|a, b| {
+            match a[0].cmp(&b[0]) {
+                std::cmp::Ordering::Equal => a[1].cmp(&b[1]),
+                other => other,
+            }
+        });
+        let password = computers.into_iter()
+            .map(|computer| computer.iter().collect::<String>())
+            .join(",");
+        password.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day23;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day23>();
+    }
+}
@@ -0,0 +1,554 @@
+use std::rc::Rc;
+
+use fxhash::FxHashMap;
+use itertools::{iproduct, Itertools};
+use petgraph::{dot::Dot, graph::{DiGraph, NodeIndex}, visit::{IntoNodeReferences, NodeFiltered, Topo}, Direction};
+use regex::Regex;
+
+use crate::{checked, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Part, Solution}, trace::{debug, trace}};
+
+const SMALL_EXAMPLE: &str = r"x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+
+const LARGE_EXAMPLE: &str = r"x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Gate {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    name: Rc<str>,
+    gate: Option<Gate>,
+}
+
+impl Node {
+    fn new(name: Rc<str>) -> Self {
+        Self {
+            name,
+            gate: None,
+        }
+    }
+}
+
+/// Builds the graph from the gate lines, e.g. `x00 AND y00 -> z00`.
+fn build_graph<'a>(gates: impl Iterator<Item = &'a str>) -> Result<DiGraph<Node, ()>, ParseError> {
+    let mut graph: DiGraph<Node, ()> = Default::default();
+    let mut node_indices: FxHashMap<Rc<str>, NodeIndex> = Default::default();
+
+    let gates_regex = Regex::new(r"^(?<a>\w{3}) (?<gate>\w+) (?<b>\w{3}) -> (?<o>\w{3})$").unwrap();
+    gates
+        .map(|line| {
+            let captures = gates_regex.captures(line)
+                .ok_or_else(|| ParseError::expected(line, "a gate like `x00 AND y00 -> z00`"))?;
+            let group = |name| captures.name(name).expect("Every group is required by the regex").as_str();
+
+            let a: Rc<str> = Rc::from(group("a"));
+            let b: Rc<str> = Rc::from(group("b"));
+            let o: Rc<str> = Rc::from(group("o"));
+            let gate = match group("gate") {
+                "AND" => Gate::And,
+                "OR" => Gate::Or,
+                "XOR" => Gate::Xor,
+                other => return Err(ParseError::expected(other, "`AND`, `OR` or `XOR`")),
+            };
+
+            Ok((a, b, o, gate))
+        })
+        .try_for_each(|gate: Result<_, ParseError>| {
+            let (a, b, o, gate) = gate?;
+            let a = *node_indices.entry(a).or_insert_with_key(|a| graph.add_node(Node::new(a.clone())));
+            let b = *node_indices.entry(b).or_insert_with_key(|b| graph.add_node(Node::new(b.clone())));
+            let o = *node_indices.entry(o).or_insert_with_key(|o| graph.add_node(Node::new(o.clone())));
+
+            let node = graph.node_weight_mut(o).unwrap();
+            node.gate.replace(gate);
+
+            graph.add_edge(a, o, ());
+            graph.add_edge(b, o, ());
+            Ok(())
+        })?;
+
+    Ok(graph)
+}
+
+fn resolve_graph(graph: &DiGraph<Node, ()>, assignments: &mut FxHashMap<Rc<str>, bool>) {
+    let filtered = NodeFiltered::from_fn(graph, |node| graph.node_weight(node).is_some_and(|g| g.gate.is_some()));
+
+    let mut visit = Topo::new(&filtered);
+    while let Some(n) = visit.next(&filtered) {
+        let Some((a, b)) = graph.neighbors_directed(n, Direction::Incoming).next_tuple() else { continue };
+
+        let Some(&a) = assignments.get(&graph.node_weight(a).unwrap().name) else { continue };
+        let Some(&b) = assignments.get(&graph.node_weight(b).unwrap().name) else { continue };
+
+        let Node { name,gate } = graph.node_weight(n).unwrap();
+        let v = assignments.entry(name.clone()).or_default();
+        match gate.as_ref().expect("Gate not initialized") {
+            Gate::And => *v = a && b,
+            Gate::Or =>  *v = a || b,
+            Gate::Xor => *v = a != b,
+        };
+    }
+}
+
+/// The number on the wires named `prefix` and a bit index, e.g. `z00`, `z01`, ...
+fn read_number(assignments: &Assignments, prefix: char) -> u64 {
+    assignments.iter()
+        .filter_map(|(name, &v)| Some((name.strip_prefix(prefix)?.parse::<u32>().ok()?, v)))
+        .filter(|&(i, _)| i < u64::BITS)
+        .fold(0u64, |acc, (i, v)| acc | (u64::from(v) << i))
+}
+
+type Assignments = FxHashMap<Rc<str>, bool>;
+
+fn initial_output(graph: &DiGraph<Node, ()>, assignments: &Assignments) -> u64 {
+    let mut assignments = assignments.clone();
+    resolve_graph(graph, &mut assignments);
+    read_number(&assignments, 'z')
+}
+
+#[derive(Clone, Copy)]
+enum Value {
+    Unknown,
+    Visiting,
+    Known(u64),
+}
+
+/// Additions to try the circuit on, 64 at once: bit `l` of each entry belongs to the `l`th addition.
+struct Additions {
+    x: Vec<u64>,
+    y: Vec<u64>,
+    sum: Vec<u64>,
+}
+
+impl Additions {
+    fn new(pairs: &[(u64, u64)], input_bits: usize, output_bits: usize) -> Self {
+        let lanes = |bits: usize, value: &dyn Fn(u64, u64) -> u64| (0..bits)
+            .map(|i| pairs.iter()
+                .enumerate()
+                .fold(0, |acc, (lane, &(x, y))| acc | ((value(x, y) >> i) & 1) << lane))
+            .collect::<Vec<_>>();
+
+        Self {
+            x: lanes(input_bits, &|x, _| x),
+            y: lanes(input_bits, &|_, y| y),
+            sum: lanes(output_bits, &|x, y| x + y),
+        }
+    }
+}
+
+/// The adder as plain lists of wires, for checking part 2 by brute force.
+#[derive(Clone)]
+struct Circuit {
+    names: Vec<Rc<str>>,
+    /// The gate that sets each wire and the two wires it reads, or `None` for an input.
+    drivers: Vec<Option<(usize, Gate, usize)>>,
+    xs: Vec<usize>,
+    ys: Vec<usize>,
+    zs: Vec<usize>,
+}
+
+impl Circuit {
+    fn new(graph: &DiGraph<Node, ()>) -> Self {
+        let names = graph.node_weights()
+            .map(|node| node.name.clone())
+            .collect::<Vec<_>>();
+        let drivers = graph.node_references()
+            .map(|(idx, node)| {
+                let gate = node.gate?;
+                let (a, b) = graph.neighbors_directed(idx, Direction::Incoming).next_tuple()?;
+                Some((a.index(), gate, b.index()))
+            })
+            .collect();
+        let wires = |prefix: char| (0..)
+            .map_while(|i| names.iter().position(|name| **name == format!("{prefix}{i:02}")))
+            .collect::<Vec<_>>();
+
+        Self { xs: wires('x'), ys: wires('y'), zs: wires('z'), names, drivers }
+    }
+
+    fn value(&self, wire: usize, values: &mut [Value]) -> Option<u64> {
+        match values[wire] {
+            Value::Known(value) => return Some(value),
+            Value::Visiting => return None,
+            Value::Unknown => {},
+        }
+
+        let (a, gate, b) = self.drivers[wire]?;
+        values[wire] = Value::Visiting;
+        let (a, b) = (self.value(a, values)?, self.value(b, values)?);
+        let value = match gate {
+            Gate::And => a & b,
+            Gate::Or => a | b,
+            Gate::Xor => a ^ b,
+        };
+        values[wire] = Value::Known(value);
+
+        Some(value)
+    }
+
+    /// The value of every output bit, or `None` if the wiring goes round in a loop.
+    fn outputs(&self, additions: &Additions) -> Option<Vec<u64>> {
+        let mut values = vec![Value::Unknown; self.names.len()];
+        self.xs.iter().zip(&additions.x).for_each(|(&wire, &x)| values[wire] = Value::Known(x));
+        self.ys.iter().zip(&additions.y).for_each(|(&wire, &y)| values[wire] = Value::Known(y));
+
+        self.zs.iter()
+            .map(|&wire| self.value(wire, &mut values))
+            .collect()
+    }
+
+    /// The lowest output bit that's wrong for any of the additions, which is `zs.len()` if none are.
+    fn first_wrong_bit(&self, additions: &Additions) -> Option<usize> {
+        let outputs = self.outputs(additions)?;
+        let first_wrong = outputs.iter()
+            .zip(&additions.sum)
+            .position(|(output, sum)| output != sum)
+            .unwrap_or(self.zs.len());
+
+        Some(first_wrong)
+    }
+
+    /// Tries swapping every pair of outputs, keeping any swap that fixes the lowest wrong bit,
+    /// until the adder is right after exactly `swaps` swaps. The swapped wires are left in `swapped`.
+    fn fix(&mut self, additions: &Additions, swaps: usize, swapped: &mut Vec<usize>) -> bool {
+        let Some(first_wrong) = self.first_wrong_bit(additions) else { return false };
+        if first_wrong == self.zs.len() || swaps == 0 {
+            return first_wrong == self.zs.len() && swaps == 0;
+        }
+
+        let outputs = (0..self.drivers.len())
+            .filter(|&wire| self.drivers[wire].is_some())
+            .collect::<Vec<_>>();
+
+        for (a, b) in outputs.into_iter().tuple_combinations() {
+            self.drivers.swap(a, b);
+            if self.first_wrong_bit(additions).is_some_and(|bit| bit > first_wrong) {
+                swapped.extend([a, b]);
+                if self.fix(additions, swaps - 1, swapped) {
+                    return true;
+                }
+                swapped.truncate(swapped.len() - 2);
+            }
+            self.drivers.swap(a, b);
+        }
+
+        false
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = (DiGraph<Node, ()>, Assignments);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: SMALL_EXAMPLE, part_1: Some("4"), part_2: None },
+        Example { input: LARGE_EXAMPLE, part_1: Some("2024"), part_2: None },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+
+        let assignments: Assignments = lines.by_ref()
+            .take_while(|l| !l.is_empty())
+            .map(|l| {
+                let (name, value) = parse::split_once(l, ": ", "a wire like `x00: 1`")?;
+                let name: Rc<str> = Rc::from(name);
+                let value = match value {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(ParseError::expected(value, "`0` or `1`")),
+                };
+
+                Ok((name, value))
+            })
+            .try_collect()?;
+
+        let graph = build_graph(lines)?;
+
+        Ok((graph, assignments))
+    }
+
+    /// A ripple-carry adder for `size`-bit numbers (at most 45, like the puzzle's),
+    /// with four pairs of gate outputs swapped, each within one bit's full adder.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let bits = size.clamp(2, 45);
+
+        // three-letter wires that can't be mistaken for inputs or outputs
+        let mut wires = iproduct!('a'..='w', 'a'..='w', 'a'..='w')
+            .map(|(a, b, c)| format!("{a}{b}{c}"))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut wires);
+        let mut wire = || wires.pop().expect("There are enough wires");
+        let (x, y, z) = (|i| format!("x{i:02}"), |i| format!("y{i:02}"), |i| format!("z{i:02}"));
+
+        let mut carry = wire();
+        let mut gates = vec![
+            [x(0), "XOR".to_owned(), y(0), z(0)],
+            [x(0), "AND".to_owned(), y(0), carry.clone()],
+        ];
+
+        // sum = x ^ y, direct = x & y, z = sum ^ carry, indirect = sum & carry, carry_out = direct | indirect
+        let full_adders = (1..bits)
+            .map(|i| {
+                let (sum, direct, indirect) = (wire(), wire(), wire());
+                let carry_out = if i == bits - 1 { z(bits) } else { wire() };
+                let start = gates.len();
+
+                gates.extend([
+                    [x(i), "XOR".to_owned(), y(i), sum.clone()],
+                    [x(i), "AND".to_owned(), y(i), direct.clone()],
+                    [sum.clone(), "XOR".to_owned(), carry.clone(), z(i)],
+                    [sum, "AND".to_owned(), carry.clone(), indirect.clone()],
+                    [direct, "OR".to_owned(), indirect, carry_out.clone()],
+                ]);
+                carry = carry_out;
+
+                start
+            })
+            .collect::<Vec<_>>();
+
+        // neither gate in each of these pairs reads the other's output, so swapping can't make a loop
+        const SWAPS: [(usize, usize); 3] = [(0, 1), (2, 3), (2, 4)];
+        let mut swapped = full_adders[..full_adders.len() - 1].to_vec();
+        rng.shuffle(&mut swapped);
+        swapped.into_iter()
+            .take(4)
+            .for_each(|start| {
+                let (a, b) = *rng.choose(&SWAPS);
+                let (out_a, out_b) = (gates[start + a][3].clone(), gates[start + b][3].clone());
+                gates[start + a][3] = out_b;
+                gates[start + b][3] = out_a;
+            });
+
+        let inputs = (0..2 * bits)
+            .map(|i| format!("{}{:02}: {}\n", if i < bits { 'x' } else { 'y' }, i % bits, rng.below(2)))
+            .collect::<String>();
+
+        rng.shuffle(&mut gates);
+        let gates = gates.into_iter()
+            .map(|[a, gate, b, out]| {
+                let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+                format!("{a} {gate} {b} -> {out}\n")
+            })
+            .collect::<String>();
+
+        Some(inputs + "\n" + &gates)
+    }
+
+    fn part_1((graph, assignments): &Self::Input) -> Answer {
+        initial_output(graph, assignments).into()
+    }
+
+    fn reference(part: Part, (graph, assignments): &Self::Input) -> Option<Answer> {
+        let circuit = Circuit::new(graph);
+        let number = |wires: &[usize]| wires.iter()
+            .rev()
+            .fold(0u64, |acc, &wire| (acc << 1) | u64::from(assignments[&circuit.names[wire]]));
+
+        match part {
+            Part::One => {
+                let additions = Additions::new(&[(number(&circuit.xs), number(&circuit.ys))], circuit.xs.len(), circuit.zs.len());
+                let output = circuit.outputs(&additions)?
+                    .into_iter()
+                    .rev()
+                    .fold(0u64, |acc, bit| (acc << 1) | bit);
+
+                Some(output.into())
+            },
+            Part::Two => {
+                let mut rng = Rng::new(24);
+                let bound = 1 << circuit.xs.len();
+                let pairs = (0..64)
+                    .map(|_| (rng.below(bound), rng.below(bound)))
+                    .collect::<Vec<_>>();
+                let additions = Additions::new(&pairs, circuit.xs.len(), circuit.zs.len());
+
+                let mut swapped = Vec::new();
+                if !circuit.clone().fix(&additions, 4, &mut swapped) {
+                    return None;
+                }
+
+                let wires = swapped.into_iter()
+                    .map(|wire| circuit.names[wire].to_string())
+                    .sorted()
+                    .collect::<Vec<_>>();
+                Some(wires.into())
+            },
+        }
+    }
+
+    fn part_2((graph, assignments): &Self::Input) -> Answer {
+        let output = initial_output(graph, assignments);
+
+        let expected_out = checked::add(read_number(assignments, 'x'), read_number(assignments, 'y'), "the expected output");
+        let incorrect_bits = expected_out ^ output;
+        debug!("incorrect bits: {incorrect_bits:048b}");
+
+        // the last carry is the top bit of the output, e.g. z45 for the puzzle's 45-bit adder
+        let bits = assignments.keys().filter(|name| name.starts_with('x')).count();
+        let carry_out = format!("z{bits:02}");
+
+        // Rule 1: all outputs must be XORs (except the last carry)
+        let non_xor_zs = graph.node_references()
+            .filter(|(_, node)| {
+                node.name.starts_with("z")
+                && node.gate != Some(Gate::Xor)
+                && *node.name != *carry_out
+            })
+            .collect::<Vec<_>>();
+        debug!("outputs that aren't XORs: {non_xor_zs:?}");
+
+        // Rule 2: all non-input gates must be AND/ORs
+        let non_andor_xys = graph.node_references()
+            .filter(|(idx, node)| {
+                !node.name.starts_with("z")
+                && node.gate == Some(Gate::Xor)
+                && !graph.neighbors_directed(*idx, Direction::Incoming)
+                    .all(|node| {
+                        let name = &graph.node_weight(node).unwrap().name;
+                        name.starts_with("x") || name.starts_with("y")
+                    })
+            })
+            .collect::<Vec<_>>();
+        debug!("XORs that aren't outputs and don't read inputs: {non_andor_xys:?}");
+
+        // Rule 3: all ANDs must lead into ORs
+        let non_or_ands = graph.node_references()
+            .filter(|(idx, node)| {
+                node.gate == Some(Gate::And)
+                && !graph.neighbors_directed(*idx, Direction::Outgoing)
+                    .all(|node| {
+                        let gate = graph.node_weight(node).unwrap().gate;
+                        gate == Some(Gate::Or)
+                    })
+                && !graph.neighbors_directed(*idx, Direction::Incoming)
+                    .all(|node| {
+                        let name = &graph.node_weight(node).unwrap().name;
+                        name.as_str() == "x00" || name.as_str() == "y00"
+                    })
+            })
+            .collect::<Vec<_>>();
+        debug!("ANDs that don't lead into ORs: {non_or_ands:?}");
+
+        // Rule 4: all XORs with inputs must lead into another XOR
+        let non_xor_xors = graph.node_references()
+            .filter(|(idx, node)| {
+                node.gate == Some(Gate::Xor)
+                && graph.neighbors_directed(*idx, Direction::Incoming)
+                    .all(|node| {
+                        let name = &graph.node_weight(node).unwrap().name;
+                        name.starts_with("x") || name.starts_with("y")
+                    })
+                && !graph.neighbors_directed(*idx, Direction::Outgoing)
+                    .any(|node| {
+                        let gate = graph.node_weight(node).unwrap().gate;
+                        gate == Some(Gate::Xor)
+                    })
+                && !graph.neighbors_directed(*idx, Direction::Incoming)
+                    .all(|node| {
+                        let name = &graph.node_weight(node).unwrap().name;
+                        name.as_str() == "x00" || name.as_str() == "y00"
+                    })
+            })
+            .collect::<Vec<_>>();
+        debug!("XORs of inputs that don't lead into XORs: {non_xor_xors:?}");
+
+        let mut all_incorrect = [non_xor_zs, non_andor_xys, non_or_ands, non_xor_xors].concat();
+        all_incorrect.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+
+        trace!("{:?}", Dot::new(graph));
+
+        all_incorrect.into_iter()
+            .map(|(_, node)| node.name.to_string())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checked::assert_overflows, cross_check::cross_check, solution::{assert_examples, Day, Solution}};
+
+    use super::Day24;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day24>();
+    }
+
+    #[test]
+    fn reports_inputs_too_wide_to_add() {
+        let inputs = (0..128).map(|i| format!("{}{:02}: 1\n", if i < 64 { 'x' } else { 'y' }, i % 64)).collect::<String>();
+        let input = Day24::parse(&(inputs + "\nx00 XOR y00 -> z00")).unwrap();
+        assert_overflows("the expected output", || Day24::part_2(&input));
+    }
+
+    #[test]
+    fn rules_find_the_same_swaps_as_brute_force() {
+        let compared = cross_check(&Day::new::<Day24>(24), 0..3, 45).unwrap();
+        assert_eq!(compared, 6);
+
+        let compared = cross_check(&Day::new::<Day24>(24), 0..3, 8).unwrap();
+        assert_eq!(compared, 6);
+    }
+}
@@ -0,0 +1,136 @@
+use itertools::{iproduct, Either, Itertools};
+
+use crate::{checked, generate::Rng, grid::Grid, parse::ParseError, solution::{Answer, Example, Solution}, trace::info};
+
+const EXAMPLE: &str = r"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("3"), part_2: None },
+    ];
+
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let schematics = input.split("\n\n")
+            .map(|chunk| {
+                let schematic = Grid::parse(chunk, |c| matches!(c, '#' | '.').then_some(()))?;
+                if (schematic.rows(), schematic.cols()) != (7, 5) {
+                    return Err(ParseError::expected(chunk, "a lock or key 5 wide and 7 tall"));
+                }
+
+                let is_lock = chunk.lines().next().unwrap().chars().all(|c| c == '#');
+                let lines_iter: Box<dyn Iterator<Item = &str>> = if is_lock {
+                    Box::new(chunk.lines())
+                } else {
+                    Box::new(chunk.lines().rev())
+                };
+
+                let lengths = lines_iter
+                    .skip(1)
+                    .fold(vec![0; 5], |acc, line| {
+                        acc.into_iter()
+                            .zip(line.chars())
+                            .map(|(acc, c)| {
+                                if c == '#' { acc + 1 } else { acc }
+                            })
+                            .collect::<Vec<usize>>()
+                    });
+                
+                Ok((is_lock, lengths))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(schematics.into_iter()
+            .partition_map(|(is_lock, length)| if is_lock { Either::Left(length) } else { Either::Right(length) }))
+    }
+
+    /// `size` locks and keys.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let schematics = (0..size)
+            .map(|_| {
+                let is_lock = rng.chance(0.5);
+                let heights = (0..5).map(|_| rng.range(0..=5) as usize).collect::<Vec<_>>();
+
+                // locks are filled from the top row down, keys from the bottom row up
+                let schematic = Grid::from_fn(7, 5, |pos| {
+                    let row = if is_lock { pos[0] } else { 6 - pos[0] };
+                    if row <= heights[pos[1]] { '#' } else { '.' }
+                });
+                schematic.to_string()
+            })
+            .join("\n");
+
+        Some(schematics)
+    }
+
+    fn part_1((locks, keys): &Self::Input) -> Answer {
+        info!("{} locks, {} keys", locks.len(), keys.len());
+        let pairs = iproduct!(locks, keys)
+            .filter(|(lock, key)| {
+                lock.iter().zip(key.iter())
+                    .all(|(&lock_len, &key_len)| checked::add(lock_len, key_len, "a lock and key's heights") <= 5)
+            })
+            .count();
+
+        pairs.into()
+    }
+
+    fn part_2(_: &Self::Input) -> Answer {
+        unreachable!("Day 25 only has one puzzle")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day25;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day25>();
+    }
+}
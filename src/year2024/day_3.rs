@@ -0,0 +1,107 @@
+use regex::Regex;
+
+use crate::{checked::{self, Sum}, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+const DO_DONT_EXAMPLE: &str = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The instructions that survived the corruption, in order
+    type Input = Vec<Instruction>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("161"), part_2: None },
+        Example { input: DO_DONT_EXAMPLE, part_1: None, part_2: Some("48") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // the puzzle only counts operands of 1-3 digits, so e.g. `mul(1234,5)` is just more corruption
+        let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").expect("Couldn't make regex");
+
+        re.captures_iter(input)
+            .map(|c| match (c.get(1), c.get(2)) {
+                (Some(a), Some(b)) => Ok(Instruction::Mul(parse::number(a.as_str())?, parse::number(b.as_str())?)),
+                _ if &c[0] == "do()" => Ok(Instruction::Do),
+                _ => Ok(Instruction::Dont),
+            })
+            .collect()
+    }
+
+    /// `size` instructions, mostly `mul`s, hidden among corrupted ones.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const NOISE: &[&str] = &[
+            "mul(4*", "mul ( 2 , 4 )", "mul[3,7]", "?(12,34)", "don't", "do(", ")", "%", "&", "#!",
+            "select()", "what()", "from()", "mul(6,9!", "+", " ", "'", "[", "@", "mul(32,", ",64)",
+        ];
+
+        let memory = (0..size)
+            .map(|i| {
+                let instruction = match rng.below(10) {
+                    0 => "do()".to_owned(),
+                    1 => "don't()".to_owned(),
+                    _ => format!("mul({},{})", rng.range(1..=999), rng.range(1..=999)),
+                };
+                let noise = (0..rng.range(0..=4))
+                    .map(|_| *rng.choose(NOISE))
+                    .collect::<String>();
+                let newline = if i % 50 == 49 { "\n" } else { "" };
+
+                noise + &instruction + newline
+            })
+            .collect::<String>();
+
+        Some(memory + "\n")
+    }
+
+    fn part_1(instructions: &Self::Input) -> Answer {
+        let total: u64 = instructions.iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Mul(a, b) => Some(a * b),
+                _ => None,
+            })
+            .checked_sum("the total of the multiplications");
+
+        total.into()
+    }
+
+    fn part_2(instructions: &Self::Input) -> Answer {
+        let total_do_dont: u64 = instructions.iter()
+            .fold((0, true), |(acc, on), instruction| match instruction {
+                Instruction::Mul(a, b) if on => (checked::add(acc, a * b, "the total of the enabled multiplications"), true),
+                Instruction::Do => (acc, true),
+                Instruction::Dont => (acc, false),
+                _ => (acc, on),
+            })
+            .0;
+
+        total_do_dont.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Solution};
+
+    use super::Day3;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day3>();
+    }
+
+    #[test]
+    fn skips_operands_of_more_than_three_digits() {
+        let instructions = Day3::parse("mul(2,4)mul(1234,5)mul(123456789012345678901,2)mul(999,999)").unwrap();
+        assert_eq!(Day3::part_1(&instructions).to_string(), "998009");
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{checked::Sum, generate::Rng, grid::GridError, parse::{ParseError, ParseErrorKind}, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+fn dirs_to_check((row, col): (usize, usize), (max_row, max_col): (usize, usize)) -> Vec<Vec<(usize, usize)>> {
+    let row = row as isize;
+    let col = col as isize;
+    (-1..=1)
+        .flat_map(|i: isize| {
+            (-1..=1).filter_map(move |j: isize| {
+                let is_valid = (i != 0 || j != 0)
+                    && row + (i * 3) >= 0
+                    && col + (j * 3) >= 0
+                    && row + (i * 3) < (max_row as isize)
+                    && col + (j * 3) < (max_col as isize);
+
+                is_valid.then(|| {
+                    (0..=3)
+                        .map(|d| ((row + i * d) as usize, (col + j * d) as usize))
+                        .collect::<Vec<_>>()
+                })
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
+fn crosses_to_check((row, col): (usize, usize), (max_row, max_col): (usize, usize)) -> Vec<Vec<(usize, usize)>> {
+    let row = row as isize;
+    let col = col as isize;
+    let max_row = max_row as isize;
+    let max_col = max_col as isize;
+    [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+        .into_iter()
+        .filter_map(|(i, j)| {
+            if row + i >= max_row
+                || row - i >= max_row
+                || row + i < 0
+                || row - i < 0
+                || col + j >= max_col
+                || col - j >= max_col
+                || col + j < 0
+                || col - j < 0
+            {
+                None
+            } else {
+                Some(vec![
+                    ((row + i) as usize, (col + j) as usize),
+                    (row as usize, col as usize),
+                    ((row - i) as usize, (col - j) as usize),
+                ])
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Vec<char>>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("18"), part_2: Some("9") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let expected = input.lines().next().map_or(0, |l| l.chars().count());
+
+        input
+            .lines()
+            .map(|l| {
+                let chars = l.chars().collect::<Vec<_>>();
+                if chars.len() == expected {
+                    Ok(chars)
+                } else {
+                    let ragged = GridError::Ragged { expected, found: chars.len() };
+                    Err(ParseError::new(l, ParseErrorKind::Grid(ragged)))
+                }
+            })
+            .collect()
+    }
+
+    /// A `size` × `size` word search of nothing but `X`, `M`, `A` and `S`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let letters = (0..size)
+            .map(|_| {
+                (0..size).map(|_| *rng.choose(&['X', 'M', 'A', 'S'])).collect::<String>() + "\n"
+            })
+            .collect();
+
+        Some(letters)
+    }
+
+    fn part_1(line_chars: &Self::Input) -> Answer {
+        let max_row = line_chars.len();
+        let max_col = line_chars[0].len();
+
+        let matches = line_chars
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                line.iter()
+                    .enumerate()
+                    .filter(|&(_, char)| *char == 'X')
+                    .map(|(col, _)| {
+                        dirs_to_check((row, col), (max_row, max_col))
+                            .iter()
+                            .filter(|d| {
+                                d.iter()
+                                    .zip(['X', 'M', 'A', 'S'])
+                                    .all(|((i, j), c)| line_chars[*i][*j] == c)
+                            })
+                            .count()
+                    })
+                    .checked_sum("the XMASes")
+            })
+            .checked_sum("the XMASes");
+
+        matches.into()
+    }
+
+    fn part_2(line_chars: &Self::Input) -> Answer {
+        let max_row = line_chars.len();
+        let max_col = line_chars[0].len();
+
+        let xmas_matches = line_chars
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                line.iter()
+                    .enumerate()
+                    .filter(|(col, char)| {
+                        (**char == 'A')
+                            .then(|| {
+                                crosses_to_check((row, *col), (max_row, max_col))
+                                    .into_iter()
+                                    .filter(|x| {
+                                        x.iter()
+                                            .zip(['M', 'A', 'S'])
+                                            .all(|((i, j), c)| line_chars[*i][*j] == c)
+                                    })
+                                    .count()
+                                    >= 2
+                            })
+                            .is_some_and(|b| b)
+                    })
+                    .count()
+            })
+            .checked_sum("the X-MASes");
+
+        xmas_matches.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day4;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day4>();
+    }
+}
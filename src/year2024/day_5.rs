@@ -0,0 +1,157 @@
+use std::{cmp::Ordering, collections::BTreeSet};
+
+use itertools::Itertools;
+
+use crate::{checked::Sum, generate::Rng, parse::{self, ParseError}, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+type Rules = BTreeSet<(u32, u32)>;
+
+fn is_ordered(pages: &[u32], ordering_rules: &Rules) -> bool {
+    pages
+        .iter()
+        .copied()
+        .tuple_combinations::<(u32, u32)>()
+        .all(|(first, second)| !ordering_rules.contains(&(second, first)))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Rules, Vec<Vec<u32>>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("143"), part_2: Some("123") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let ordering_rules = input
+            .lines()
+            .take_while(|s| !s.trim().is_empty())
+            .map(|s| {
+                let (before, after) = parse::split_once(s, "|", "a rule like `47|53`")?;
+                Ok((parse::number::<u32>(before)?, parse::number::<u32>(after)?))
+            })
+            .collect::<Result<BTreeSet<_>, ParseError>>()?;
+
+        let pages_to_produce = input
+            .lines()
+            .skip_while(|s| !s.trim().is_empty())
+            .skip(1)
+            .map(|s| {
+                s.split(",")
+                    .map(parse::number::<u32>)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<Vec<_>>, _>>()?;
+
+        Ok((ordering_rules, pages_to_produce))
+    }
+
+    /// `size` updates of 49 pages, with a rule for every pair of pages. About half are in order.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut pages = (10..100).collect::<Vec<u32>>();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+
+        let mut rules = pages.iter()
+            .tuple_combinations()
+            .map(|(before, after)| format!("{before}|{after}\n"))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut rules);
+
+        let updates = (0..size)
+            .map(|_| {
+                let mut update = pages.clone();
+                rng.shuffle(&mut update);
+                update.truncate(2 * rng.index(12) + 5);
+                if rng.chance(0.5) {
+                    update.sort_by_key(|page| pages.iter().position(|p| p == page));
+                }
+
+                update.iter().join(",") + "\n"
+            })
+            .collect::<String>();
+
+        Some(rules.concat() + "\n" + &updates)
+    }
+
+    fn part_1((ordering_rules, pages_to_produce): &Self::Input) -> Answer {
+        let sum_of_valid_middle = pages_to_produce
+            .iter()
+            .filter(|pages| is_ordered(pages, ordering_rules))
+            .map(|pages| {
+                let middle_idx = pages.len() / 2;
+                pages[middle_idx] as u64
+            })
+            .checked_sum("the total of the middle pages");
+
+        sum_of_valid_middle.into()
+    }
+
+    fn part_2((ordering_rules, pages_to_produce): &Self::Input) -> Answer {
+        let sum_of_corrected_middle = pages_to_produce
+            .iter()
+            .filter(|pages| !is_ordered(pages, ordering_rules))
+            .map(|pages| {
+                let mut pages = pages.clone();
+                pages.sort_by(|&a, &b| {
+                    if ordering_rules.contains(&(a, b)) {
+                        Ordering::Less
+                    } else if ordering_rules.contains(&(b, a)) {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                });
+                pages
+            })
+            .map(|pages| {
+                let middle_idx = pages.len() / 2;
+                pages[middle_idx] as u64
+            })
+            .checked_sum("the total of the middle pages");
+
+        sum_of_corrected_middle.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day5;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day5>();
+    }
+}
@@ -0,0 +1,218 @@
+use std::{collections::HashSet, io};
+
+use ndarray::Ix2;
+
+use crate::{checked::{self, Sum}, direction::Direction, frames::{Frame, FrameSink}, generate::Rng, grid::{Grid, GridError}, parse::{self, ParseError, ParseErrorKind}, solution::{Answer, Example, Part, Solution}, trace::debug};
+
+const EXAMPLE: &str = r"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+#[derive(PartialEq, Clone)]
+pub enum TileState {
+    Empty,
+    Visited,
+    Wall,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct GuardPosition {
+    pos: Ix2,
+    dir: Direction,
+}
+
+impl GuardPosition {
+    fn move_guard(&mut self, map: &[Vec<TileState>]) -> bool {
+        let next_tile = self.dir.offset(self.pos)
+            .and_then(|next| Some((next, map.get(next[0])?.get(next[1])?)));
+
+        match next_tile {
+            None => true,
+            Some((_, TileState::Wall)) => {
+                self.dir = self.dir.turn_right();
+                false
+            },
+            Some((next, TileState::Empty | TileState::Visited)) => {
+                self.pos = next;
+                false
+            },
+        }
+    }
+}
+
+/// The lab with the guard in it, and an `X` everywhere it's been.
+fn frame(map: &[Vec<TileState>], guard_pos: &GuardPosition) -> Frame {
+    Grid::from_fn(map.len(), map.first().map_or(0, Vec::len), |pos| match map[pos[0]][pos[1]] {
+        _ if pos == guard_pos.pos => guard_pos.dir.into(),
+        TileState::Empty => '.',
+        TileState::Visited => 'X',
+        TileState::Wall => '#',
+    })
+}
+
+fn walk(map: &[Vec<TileState>], guard_pos: &GuardPosition, sink: &mut dyn FrameSink) -> io::Result<()> {
+    let mut map = map.to_vec();
+    let mut guard_pos = guard_pos.clone();
+
+    sink.push(&frame(&map, &guard_pos))?;
+    while !guard_pos.move_guard(&map) {
+        map[guard_pos.pos[0]][guard_pos.pos[1]] = TileState::Visited;
+        sink.push(&frame(&map, &guard_pos))?;
+    }
+
+    Ok(())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Vec<Vec<TileState>>, GuardPosition);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("41"), part_2: Some("6") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut guard_pos: Option<GuardPosition> = None;
+        let map = input
+            .lines()
+            .enumerate()
+            .map(|(row, l)| {
+                l.char_indices()
+                    .enumerate()
+                    .map(|(col, (i, c))| {
+                        match c {
+                            '.' => Ok(TileState::Empty),
+                            '#' => Ok(TileState::Wall),
+                            _ => {
+                                let invalid = || ParseError::new(&l[i..i + c.len_utf8()], ParseErrorKind::Grid(GridError::InvalidChar(c)));
+                                let dir = c.try_into().map_err(|_| invalid())?;
+                                guard_pos = Some(GuardPosition {
+                                    pos: Ix2(row, col),
+                                    dir,
+                                });
+                                Ok(TileState::Visited)
+                            }
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let guard_pos = guard_pos.ok_or_else(|| ParseError::expected(parse::end_of(input), "a guard (`^`, `>`, `v` or `<`)"))?;
+
+        Ok((map, guard_pos))
+    }
+
+    /// A `size` × `size` lab that the guard walks out of, rather than around forever.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        loop {
+            let map = (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.chance(0.05) { TileState::Wall } else { TileState::Empty })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let pos = Ix2(rng.index(size), rng.index(size));
+            let dir = *rng.choose(&Direction::ALL);
+            if map[pos[0]][pos[1]] == TileState::Wall { continue }
+
+            let mut guard_pos = GuardPosition { pos, dir };
+            let mut positions = HashSet::new();
+            while positions.insert(guard_pos.clone()) {
+                if guard_pos.move_guard(&map) {
+                    let input = map.iter()
+                        .enumerate()
+                        .map(|(row, tiles)| {
+                            tiles.iter()
+                                .enumerate()
+                                .map(|(col, tile)| match tile {
+                                    _ if Ix2(row, col) == pos => dir.into(),
+                                    TileState::Wall => '#',
+                                    _ => '.',
+                                })
+                                .collect::<String>() + "\n"
+                        })
+                        .collect();
+
+                    return Some(input);
+                }
+            }
+        }
+    }
+
+    fn part_1((map, guard_pos): &Self::Input) -> Answer {
+        let mut map = map.clone();
+        let mut guard_pos = guard_pos.clone();
+        let mut tiles_visited: usize = 1;
+        while !guard_pos.move_guard(&map) {
+            let (row, col) = (guard_pos.pos[0], guard_pos.pos[1]);
+            if map[row][col] == TileState::Empty {
+                tiles_visited = checked::add(tiles_visited, 1, "the tiles visited");
+                map[row][col] = TileState::Visited;
+            }
+        }
+
+        tiles_visited.into()
+    }
+
+    fn part_2((map, guard_pos): &Self::Input) -> Answer {
+        let rows = map.len();
+        let cols = map.first().unwrap().len();
+
+        let possible_obstructions = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .filter(|&col| {
+                        if map[row][col] == TileState::Wall {
+                            return false;
+                        }
+
+                        let mut positions: HashSet<GuardPosition> = HashSet::new();
+                        let mut map = map.clone();
+                        map[row][col] = TileState::Wall;
+                        let map = map;
+                        let mut guard_pos = guard_pos.clone();
+
+                        positions.insert(guard_pos.clone());
+
+                        while !guard_pos.move_guard(&map) {
+                            if !positions.insert(guard_pos.clone()) {
+                                debug!("obstruction at {row} {col} makes a loop");
+                                return true;
+                            }
+                        }
+
+                        false
+                    })
+                    .count()
+            })
+            .checked_sum("the possible obstructions");
+
+        possible_obstructions.into()
+    }
+
+    /// The guard's walk out of the lab in part 1.
+    fn animate(part: Part, (map, guard_pos): &Self::Input, sink: &mut dyn FrameSink) -> Option<io::Result<()>> {
+        (part == Part::One).then(|| walk(map, guard_pos, sink))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::assert_examples;
+
+    use super::Day6;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day6>();
+    }
+}
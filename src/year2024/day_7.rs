@@ -0,0 +1,147 @@
+use crate::{checked::Sum, generate::Rng, num::Digits, parse::{self, ParseError}, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+#[derive(Debug)]
+pub struct Equation {
+    pub result: u64,
+    pub values: Vec<u64>,
+}
+
+impl Equation {
+    fn calibrate_values(target: u64, accum: u64, values: &[u64]) -> bool {
+        if values.is_empty() {
+            accum == target
+        } else if accum > target {
+            false
+        } else {
+            // anything too big to fit is bigger than the target
+            let next = |accum: Option<u64>| accum.is_some_and(|accum| Equation::calibrate_values(target, accum, &values[1..]));
+
+            next(accum.checked_add(values[0])) ||
+            next(accum.checked_mul(values[0]))
+        }
+    }
+
+    fn calibrate_values_with_concat(target: u64, accum: u64, values: &[u64]) -> bool {
+        if values.is_empty() {
+            accum == target
+        } else if accum > target {
+            false
+        } else {
+            let next = |accum: Option<u64>| accum.is_some_and(|accum| Equation::calibrate_values_with_concat(target, accum, &values[1..]));
+
+            next(accum.checked_add(values[0])) ||
+            next(accum.checked_mul(values[0])) ||
+            next(accum.checked_concat(values[0]))
+        }
+    }
+    
+    pub fn calibrate(&self) -> bool {
+        Equation::calibrate_values(self.result, self.values[0], &self.values[1..])
+    }
+
+    pub fn calibrate_with_concat(&self) -> bool {
+        Equation::calibrate_values_with_concat(self.result, self.values[0], &self.values[1..])
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("3749"), part_2: Some("11387") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines()
+            .map(|line| {
+                let (result_str, value_str) = parse::split_once(line, ": ", "`: `")?;
+                let result: u64 = parse::number(result_str)?;
+                let values: Vec<u64> = value_str.split(' ')
+                    .map(parse::number)
+                    .try_collect()?;
+                (values.len() >= 2).then_some(()).ok_or_else(|| ParseError::expected(value_str, "at least two values"))?;
+
+                Ok(Equation { result, values })
+            })
+            .try_collect::<Vec<_>>()
+    }
+
+    /// `size` equations of two to twelve values, most of which can be made true.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let equations = (0..size)
+            .map(|_| loop {
+                let values = (0..rng.range(2..=12))
+                    .map(|_| {
+                        let max = if rng.chance(0.3) { 999 } else { 99 };
+                        rng.range(1..=max) as u64
+                    })
+                    .collect::<Vec<_>>();
+                let result = values[1..].iter()
+                    .try_fold(values[0], |acc, &value| match rng.below(3) {
+                        0 => acc.checked_add(value),
+                        1 => acc.checked_mul(value),
+                        _ => acc.checked_mul(10u64.pow(value.ilog10() + 1))?.checked_add(value),
+                    });
+
+                // small enough that concatenating a value onto any partial result still fits
+                let Some(result) = result.filter(|&result| result < 1_000_000_000_000_000) else { continue };
+                let result = if rng.chance(0.3) { result + rng.range(1..=9) as u64 } else { result };
+
+                let values = values.iter().map(u64::to_string).collect::<Vec<_>>();
+                break format!("{result}: {}\n", values.join(" "));
+            })
+            .collect();
+
+        Some(equations)
+    }
+
+    fn part_1(eqns: &Self::Input) -> Answer {
+        let total_calibration_result = eqns
+            .iter()
+            .filter(|eqn| eqn.calibrate())
+            .map(|eqn| eqn.result)
+            .checked_sum("the total calibration result");
+
+        total_calibration_result.into()
+    }
+
+    fn part_2(eqns: &Self::Input) -> Answer {
+        let total_calibration_result_with_concat = eqns
+            .iter()
+            .filter(|eqn| eqn.calibrate_with_concat())
+            .map(|eqn| eqn.result)
+            .checked_sum("the total calibration result");
+
+        total_calibration_result_with_concat.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checked::assert_overflows, solution::{assert_examples, Solution}};
+
+    use super::Day7;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day7>();
+    }
+
+    #[test]
+    fn reports_huge_totals() {
+        let input = Day7::parse("18446744073709551615: 18446744073709551614 1\n2: 1 1").unwrap();
+        assert_overflows("the total calibration result", || Day7::part_1(&input));
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use ndarray::Ix2;
+
+use crate::{checked, direction::{ix2_to_signed, ToIx2}, generate::Rng, grid::Grid, num, parse::ParseError, solution::{Answer, Example, Solution}};
+
+const EXAMPLE: &str = r"............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+type Antennae = HashMap<char, Vec<Ix2>>;
+
+fn on_map(pos: (isize, isize), rows: usize, cols: usize) -> Option<Ix2> {
+    pos.to_ix2().filter(|pos| pos[0] < rows && pos[1] < cols)
+}
+
+/// `times` steps of `step` on from `pos`, or back if `times` is negative.
+fn steps_from(pos: (isize, isize), step: (isize, isize), times: isize) -> (isize, isize) {
+    let along = |pos, step| checked::add(pos, checked::mul(step, times, "an antinode's position"), "an antinode's position");
+    (along(pos.0, step.0), along(pos.1, step.1))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Antennae, usize, usize);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("14"), part_2: Some("34") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input, |c| (c == '.' || c.is_alphanumeric()).then_some(c))?;
+
+        let mut antennae: Antennae = HashMap::new();
+        map.indexed_iter()
+            .filter(|(_, char)| char.is_alphanumeric())
+            .for_each(|(pos, &char)| {
+                let a = antennae.entry(char).or_default();
+                a.push(pos);
+            });
+
+        Ok((antennae, map.rows(), map.cols()))
+    }
+
+    /// A `size` × `size` map with up to a dozen frequencies, each with a few antennas.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let mut map = Grid::filled(size, size, '.');
+        (0..rng.range(1..=12)).for_each(|_| {
+            let frequency = *rng.choose(FREQUENCIES) as char;
+            (0..rng.range(2..=5)).for_each(|_| {
+                map[Ix2(rng.index(size), rng.index(size))] = frequency;
+            });
+        });
+
+        Some(map.to_string())
+    }
+
+    fn part_1(&(ref antennae, rows, cols): &Self::Input) -> Answer {
+        let antinodes = antennae.values()
+            .flat_map(|ants| {
+                ants.iter()
+                    .combinations(2)
+                    .flat_map(|v| {
+                        let a = ix2_to_signed(*v[0]);
+                        let b = ix2_to_signed(*v[1]);
+
+                        let diff = (a.0 - b.0, a.1 - b.1);
+                        [steps_from(a, diff, 1), steps_from(b, diff, -1)]
+                    })
+            })
+            .filter_map(|node| on_map(node, rows, cols))
+            .unique()
+            .count();
+
+        antinodes.into()
+    }
+
+    fn part_2(&(ref antennae, rows, cols): &Self::Input) -> Answer {
+        let antinodes = antennae.values()
+            .flat_map(|ants| {
+                ants.iter()
+                    .combinations(2)
+                    .flat_map(|v| {
+                        let a = ix2_to_signed(*v[0]);
+                        let b = ix2_to_signed(*v[1]);
+
+                        // the smallest step along the line, so that points between the antennae count too
+                        let diff = (a.0 - b.0, a.1 - b.1);
+                        let gcd = num::gcd(diff.0, diff.1);
+                        let step = (diff.0 / gcd, diff.1 / gcd);
+
+                        let ahead = (0..)
+                            .map_while(move |i| on_map(steps_from(a, step, i), rows, cols));
+                        let behind = (1..)
+                            .map_while(move |i| on_map(steps_from(a, step, -i), rows, cols));
+
+                        ahead.chain(behind)
+                    })
+            })
+            .unique()
+            .count();
+
+        antinodes.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Solution};
+
+    use super::Day8;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day8>();
+    }
+
+    #[test]
+    fn counts_points_between_antennae() {
+        let input = Day8::parse("a..\n...\n..a\n").unwrap();
+        assert_eq!(Day8::part_2(&input), 3.into());
+    }
+}
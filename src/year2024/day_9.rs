@@ -0,0 +1,185 @@
+use std::{fmt::Debug, iter::repeat_n};
+
+use crate::{checked::{self, Sum}, generate::Rng, parse::ParseError, solution::{Answer, Example, Solution}, trace::trace};
+
+const EXAMPLE: &str = r"2333133121414131402";
+
+// const INPUT: &str = "48454";
+
+#[derive(Clone)]
+pub struct File {
+    file_id: usize,
+    file_len: usize,
+    file_gap: usize,
+}
+
+impl Debug for File {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file_id)
+    }
+}
+
+fn render_fs(fs: &[File]) -> String {
+    fs.iter()
+        .flat_map(|file| {
+            let file_chunks = repeat_n(Some(file.file_id), file.file_len);
+            let gap_chunks = repeat_n(None, file.file_gap);
+
+            file_chunks.chain(gap_chunks)
+        })
+        .map(|block| block.map(|b| b.to_string()).unwrap_or_else(|| ".".to_owned()))
+        .collect()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<File>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("1928"), part_2: Some("2858") },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input.trim();
+        let digits = input.char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|d| d as usize)
+                    .ok_or_else(|| ParseError::expected(&input[i..i + c.len_utf8()], "a digit"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let files = digits.chunks(2)
+            .enumerate()
+            .map(|(file_id, chunk)| {
+                let file_len = chunk[0];
+                let file_gap = chunk.get(1).copied().unwrap_or(0);
+
+                File { file_id, file_len, file_gap }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(files)
+    }
+
+    /// A disk map of `size` files of one to nine blocks.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let disk_map = (0..size)
+            .map(|i| {
+                let file_len = rng.range(1..=9);
+                if i + 1 < size {
+                    format!("{file_len}{}", rng.range(0..=9))
+                } else {
+                    file_len.to_string()
+                }
+            })
+            .collect::<String>();
+
+        Some(disk_map + "\n")
+    }
+
+    fn part_1(files: &Self::Input) -> Answer {
+        let mut blocks = files.iter()
+            .flat_map(|file| {
+                let file_chunks = repeat_n(Some(file.file_id), file.file_len);
+                let gap_chunks = repeat_n(None, file.file_gap);
+
+                file_chunks.chain(gap_chunks)
+            })
+            .collect::<Vec<_>>();
+
+        (0..blocks.len()).rev()
+            .map_while(|i| {
+                let first_gap_idx = blocks.iter()
+                    .enumerate()
+                    .take(i)
+                    .find(|&(_, b)| b.is_none())?.0;
+
+                blocks[first_gap_idx] = blocks[i];
+                blocks[i] = None;
+
+                Some(())
+            })
+            .for_each(|_| ());
+
+        let checksum = blocks.iter()
+            .enumerate()
+            .filter_map(|(i, &b)| {
+                Some(checked::mul(i as u64, b? as u64, "the checksum"))
+            })
+            .checked_sum("the checksum");
+
+        checksum.into()
+    }
+
+    fn part_2(files: &Self::Input) -> Answer {
+        let mut files = files.clone();
+        trace!("{}", render_fs(&files));
+        let file_ids = files.iter().map(|f| f.file_id).collect::<Vec<_>>();
+        file_ids.into_iter()
+            .skip(1)
+            .rev()
+            .for_each(|file_id| {
+                let file_to_move = files.iter().position(|f| f.file_id == file_id).unwrap();
+                let required_len = files[file_to_move].file_len;
+                let first_sufficient_gap = files.iter().take(file_to_move).position(|f| required_len <= f.file_gap);
+
+                if let Some(gap) = first_sufficient_gap {
+                    let old_file = files[file_to_move].clone();
+                    let mut moved_file = File {
+                        file_id: old_file.file_id,
+                        file_len: old_file.file_len,
+                        file_gap: files[gap].file_gap - old_file.file_len,
+                    };
+
+                    files[gap].file_gap = 0; // set gap before inserted file to zero
+                    if gap == file_to_move - 1 { // edge case: if the available gap is immediately before
+                        moved_file.file_gap += old_file.file_len + old_file.file_gap;
+                    } else {
+                        files[file_to_move - 1].file_gap += old_file.file_len + old_file.file_gap; // fill the remaining space with gap
+                    }
+
+                    files.remove(file_to_move);
+                    files.insert(gap + 1, moved_file);
+                }
+
+                trace!("{file_id}: {}", render_fs(&files));
+            });
+
+        let blocks = files.iter()
+            .flat_map(|file| {
+                let file_chunks = repeat_n(Some(file.file_id), file.file_len);
+                let gap_chunks = repeat_n(None, file.file_gap);
+
+                file_chunks.chain(gap_chunks)
+            })
+            .collect::<Vec<_>>();
+
+        let checksum = blocks.iter()
+            .enumerate()
+            .filter_map(|(i, &b)| {
+                Some(checked::mul(i as u64, b? as u64, "the checksum"))
+            })
+            .checked_sum("the checksum");
+        checksum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{assert_examples, Solution};
+
+    use super::Day9;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day9>();
+    }
+
+    #[test]
+    fn numbers_files_past_u16() {
+        let files = Day9::parse(&"10".repeat(70_000)).unwrap();
+        assert_eq!(files.last().unwrap().file_id, 69_999);
+    }
+}